@@ -0,0 +1,77 @@
+use clap::Subcommand;
+
+use crate::git_utils::repo_info::{RepoName, RepoOwner};
+
+#[derive(Subcommand)]
+pub enum BranchCommand {
+    /// Get list of branches with commits ahead/behind default branch, last commit and protection
+    List {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+    },
+
+    /// Create branch on github
+    Create {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// New branch name
+        name: String,
+        /// Commit sha, branch or tag to start branch from, default branch if not set (optional)
+        #[clap(long, short, default_value = None)]
+        from: Option<String>,
+    },
+
+    /// Delete branch or all branches with merged pull requests
+    Delete {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Branch name (optional, picked from branches list if not set)
+        name: Option<String>,
+        /// Delete all branches which last commit is merged by pull request.
+        /// Default and protected branches are kept (optional)
+        #[clap(long, conflicts_with = "name")]
+        merged: bool,
+        /// Delete merged branches without confirmation (optional)
+        #[clap(long, short)]
+        yes: bool,
+    },
+
+    /// Rename branch, its pull requests and protection rules are moved to new name
+    Rename {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Branch name (optional, picked from branches list if not set)
+        #[clap(long, short, default_value = None)]
+        name: Option<String>,
+        /// New branch name
+        new_name: String,
+    },
+
+    /// Show commits and changed files between two commits, branches or tags
+    Compare {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Refs to compare. Example: `main...feature`
+        basehead: String,
+    },
+}
@@ -29,9 +29,10 @@ pub enum CommentCommand {
         /// Repo name (optional)
         #[clap(long, short, default_value = None)]
         repo: Option<RepoName>,
-        /// Get all comments from issue/pull request with number
-        #[clap(long, short)]
-        number: i64,
+        /// Get all comments from issue/pull request with number (optional, picked from open
+        /// issues or pull requests list by target if not set)
+        #[clap(long, short, default_value = None)]
+        number: Option<i64>,
         /// Get from issue or from pull request (can be only 'issue' or 'pull-request')
         #[clap(long, short)]
         target: CommentTarget,
@@ -45,9 +46,10 @@ pub enum CommentCommand {
         /// Repo name (optional)
         #[clap(long, short, default_value = None)]
         repo: Option<RepoName>,
-        /// Get all comments from issue/pull request with number
-        #[clap(long, short)]
-        number: i64,
+        /// Get all comments from pull request with number (optional, picked from open pull
+        /// requests list if not set)
+        #[clap(long, short, default_value = None)]
+        number: Option<i64>,
         /// Can be only 'created' or 'updated' (optional)
         #[clap(long, short, default_value = "created")]
         sort: Sorts,
@@ -64,9 +66,9 @@ pub enum CommentCommand {
         /// Repo name (optional)
         #[clap(long, short, default_value = None)]
         repo: Option<RepoName>,
-        /// Update comment from issue/pull request by id
-        #[clap(long, short)]
-        comment_id: i64,
+        /// Update comment from issue/pull request by id (optional, picked from repo comments if not set)
+        #[clap(long, short, default_value = None)]
+        comment_id: Option<i64>,
         /// Comment body (optional)
         #[clap(long, short, default_value = "")]
         body: String,
@@ -80,8 +82,8 @@ pub enum CommentCommand {
         /// Repo name (optional)
         #[clap(long, short, default_value = None)]
         repo: Option<RepoName>,
-        /// Delete comment from issue/pull request by id
-        #[clap(long, short)]
-        comment_id: i64,
+        /// Delete comment from issue/pull request by id (optional, picked from repo comments if not set)
+        #[clap(long, short, default_value = None)]
+        comment_id: Option<i64>,
     },
 }
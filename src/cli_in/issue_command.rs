@@ -1,7 +1,9 @@
-use crate::git_utils::repo_info::{RepoName, RepoOwner};
+use crate::git_utils::repo_info::{RepoInfo, RepoName, RepoOwner};
 use clap::Subcommand;
 
-use crate::cli_in::set_vars::{IssuesListStates, States};
+use crate::cli_in::set_vars::{
+    CloseReason, CommaList, IssuesListSorts, IssuesListStates, LockReasons, MilestoneRef, States,
+};
 
 #[derive(Subcommand)]
 pub enum IssueCommand {
@@ -25,6 +27,12 @@ pub enum IssueCommand {
         /// A list of comma separated label names. Example: `bug,ui,@high` (optional)
         #[clap(long, short, default_value = "")]
         labels: String,
+        /// Milestone title or number (optional)
+        #[clap(long, short, default_value = None)]
+        milestone: Option<MilestoneRef>,
+        /// Sort by. Can be either `created`, `updated`, `comments` or `reactions` (👍 count) (optional)
+        #[clap(long, default_value = "created")]
+        sort: IssuesListSorts,
         /// Page number of the results to fetch (optional)
         #[clap(long, short, default_value = "1")]
         numb_of_page: i64,
@@ -41,9 +49,22 @@ pub enum IssueCommand {
         /// Repo name (optional)
         #[clap(long, short, default_value = None)]
         repo: Option<RepoName>,
-        /// Get issue with number
-        #[clap(long, short)]
-        number: i64,
+        /// Get issue with number (optional, picked from open issues list if not set)
+        #[clap(long, short, default_value = None)]
+        number: Option<i64>,
+    },
+
+    /// Get issue timeline: comments, labels, assignments, references and commits
+    Timeline {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Issue number (optional, picked from open issues list if not set)
+        #[clap(long, short, default_value = None)]
+        number: Option<i64>,
     },
 
     /// Get issue with comment from issues list
@@ -66,6 +87,12 @@ pub enum IssueCommand {
         /// A list of comma separated label names. Example: `bug,ui,@high` (optional)
         #[clap(long, short, default_value = "")]
         labels: String,
+        /// Milestone title or number (optional)
+        #[clap(long, short, default_value = None)]
+        milestone: Option<MilestoneRef>,
+        /// Sort by. Can be either `created`, `updated`, `comments` or `reactions` (👍 count) (optional)
+        #[clap(long, default_value = "created")]
+        sort: IssuesListSorts,
         /// Page number of the results to fetch (optional)
         #[clap(long, short, default_value = "1")]
         numb_of_page: i64,
@@ -94,6 +121,12 @@ pub enum IssueCommand {
         /// A list of comma separated label names. Example: `bug,ui,@high` (optional)
         #[clap(long, short, default_value = "enhancement")]
         labels: String,
+        /// Milestone title or number (optional)
+        #[clap(long, short, default_value = None)]
+        milestone: Option<MilestoneRef>,
+        /// Check that labels exist in repo before create (optional)
+        #[clap(long)]
+        check_labels: bool,
     },
 
     /// Update issue
@@ -104,24 +137,39 @@ pub enum IssueCommand {
         /// Repo name (optional)
         #[clap(long, short, default_value = None)]
         repo: Option<RepoName>,
-        /// Update issue with number
-        #[clap(long, short)]
-        number: i64,
-        /// Issue title
+        /// Update issue with number (optional, picked from open issues list if not set)
+        #[clap(long, short, default_value = None)]
+        number: Option<i64>,
+        /// New issue title (optional)
         #[clap(long, short, default_value = None)]
         title: Option<String>,
-        /// Issue body (optional)
+        /// New issue body (optional)
         #[clap(long, short, default_value = None)]
         body: Option<String>,
-        /// A list of comma separated assignee names. Example: `aragami3070,danilasar` (optional)
+        /// Replace assignees with comma separated names, `""` removes all. Example: `aragami3070,danilasar` (optional)
         #[clap(long, short, default_value = None)]
-        assignees: Option<String>,
-        /// Indicates the state of the issues to return. Can be either `open` or `closed`(optional)
-        #[clap(long, short, default_value = "open")]
-        state: States,
-        /// A list of comma separated label names. Example: `bug,ui,@high` (optional)
+        assignees: Option<CommaList>,
+        /// New state. Can be either `open` or `closed` (optional)
         #[clap(long, short, default_value = None)]
-        labels: Option<String>,
+        state: Option<States>,
+        /// Replace labels with comma separated names, `""` removes all. Example: `bug,ui,@high` (optional)
+        #[clap(long, short, default_value = None)]
+        labels: Option<CommaList>,
+        /// Comma separated labels to add. Example: `bug,ui` (optional)
+        #[clap(long, default_value = "")]
+        add_label: CommaList,
+        /// Comma separated labels to remove. Example: `bug,ui` (optional)
+        #[clap(long, default_value = "")]
+        remove_label: CommaList,
+        /// Comma separated assignees to add. Example: `aragami3070` (optional)
+        #[clap(long, default_value = "")]
+        add_assignee: CommaList,
+        /// Comma separated assignees to remove. Example: `aragami3070` (optional)
+        #[clap(long, default_value = "")]
+        remove_assignee: CommaList,
+        /// Milestone title or number (optional)
+        #[clap(long, short, default_value = None)]
+        milestone: Option<MilestoneRef>,
     },
 
     /// Close issue
@@ -132,11 +180,104 @@ pub enum IssueCommand {
         /// Repo name (optional)
         #[clap(long, short, default_value = None)]
         repo: Option<RepoName>,
-        /// Close issue with number
-        #[clap(long, short)]
-        number: i64,
+        /// Close issue with number (optional, several issues can be picked from open issues list if not set)
+        #[clap(long, short, default_value = None)]
+        number: Option<i64>,
         /// Close with comment (optional)
         #[clap(long, short, default_value = "")]
         comment: String,
+        /// Close reason. Can be either `completed`, `not_planned` or `duplicate` (optional)
+        #[clap(long, default_value = "completed")]
+        reason: CloseReason,
+        /// Close as duplicate of issue with number and post "Duplicate of #N" comment (optional)
+        #[clap(long, default_value = None)]
+        duplicate_of: Option<i64>,
+    },
+
+    /// Reopen closed issue
+    Reopen {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Reopen issue with number (optional, several issues can be picked from closed issues list if not set)
+        #[clap(long, short, default_value = None)]
+        number: Option<i64>,
+        /// Reopen with comment (optional)
+        #[clap(long, short, default_value = "")]
+        comment: String,
+    },
+
+    /// Lock issue conversation
+    Lock {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Issue number (optional, picked from open issues list if not set)
+        #[clap(long, short, default_value = None)]
+        number: Option<i64>,
+        /// Lock reason. Can be either `off-topic`, `too heated`, `resolved` or `spam` (optional)
+        #[clap(long, default_value = None)]
+        reason: Option<LockReasons>,
+    },
+
+    /// Unlock issue conversation
+    Unlock {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Issue number (optional, picked from open issues list if not set)
+        #[clap(long, short, default_value = None)]
+        number: Option<i64>,
+    },
+
+    /// Pin issue in repo
+    Pin {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Issue number (optional, picked from open issues list if not set)
+        #[clap(long, short, default_value = None)]
+        number: Option<i64>,
+    },
+
+    /// Unpin issue in repo
+    Unpin {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Issue number (optional, picked from open issues list if not set)
+        #[clap(long, short, default_value = None)]
+        number: Option<i64>,
+    },
+
+    /// Transfer issue to other repo of the same owner
+    Transfer {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Issue number (optional, picked from open issues list if not set)
+        #[clap(long, short, default_value = None)]
+        number: Option<i64>,
+        /// Target repo. Example: `aragami3070/github-cli`
+        #[clap(long)]
+        to: RepoInfo,
     },
 }
@@ -0,0 +1,111 @@
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+use crate::cli_in::set_vars::LabelColor;
+use crate::git_utils::repo_info::{RepoInfo, RepoName, RepoOwner};
+
+#[derive(Subcommand)]
+pub enum LabelCommand {
+    /// Get list of labels
+    List {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+    },
+
+    /// Create label
+    Create {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Label name
+        #[clap(long, short)]
+        name: String,
+        /// Hex color of label. Example: `d73a4a` (optional)
+        #[clap(long, short, default_value = "ededed")]
+        color: LabelColor,
+        /// Label description (optional)
+        #[clap(long, short, default_value = "")]
+        description: String,
+    },
+
+    /// Edit label
+    Edit {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Edit label with name (optional, picked from labels list if not set)
+        #[clap(long, short, default_value = None)]
+        name: Option<String>,
+        /// New label name (optional)
+        #[clap(long, default_value = None)]
+        new_name: Option<String>,
+        /// New hex color of label. Example: `d73a4a` (optional)
+        #[clap(long, short, default_value = None)]
+        color: Option<LabelColor>,
+        /// New label description (optional)
+        #[clap(long, short, default_value = None)]
+        description: Option<String>,
+    },
+
+    /// Delete label
+    Delete {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Delete label with name (optional, several labels can be picked from labels list if not set)
+        #[clap(long, short, default_value = None)]
+        name: Option<String>,
+    },
+
+    /// Copy labels from other repo
+    Clone {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Repo to copy labels from. Example: `aragami3070/github-cli`
+        #[clap(long, short)]
+        from: RepoInfo,
+        /// Delete labels which are not in source repo (optional)
+        #[clap(long)]
+        prune: bool,
+        /// Only show changes (optional)
+        #[clap(long)]
+        dry_run: bool,
+    },
+
+    /// Apply labels from yaml file
+    Sync {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Yaml file with list of labels (`name`, `color`, `description`)
+        #[clap(long, short)]
+        file: PathBuf,
+        /// Delete labels which are not in file (optional)
+        #[clap(long)]
+        prune: bool,
+        /// Only show changes (optional)
+        #[clap(long)]
+        dry_run: bool,
+    },
+}
@@ -0,0 +1,90 @@
+use clap::Subcommand;
+
+use crate::cli_in::set_vars::{DueDate, IssuesListStates, MilestoneRef, States};
+use crate::git_utils::repo_info::{RepoName, RepoOwner};
+
+#[derive(Subcommand)]
+pub enum MilestoneCommand {
+    /// Get list of milestones with progress
+    List {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Indicates the state of the milestones to return. Can be either `open`, `closed`, or `all` (optional)
+        #[clap(long, short, default_value = "open")]
+        state: IssuesListStates,
+    },
+
+    /// Create milestone
+    Create {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Milestone title
+        #[clap(long, short)]
+        title: String,
+        /// Milestone description (optional)
+        #[clap(long, short, default_value = "")]
+        description: String,
+        /// Due date in format `YYYY-MM-DD` (optional)
+        #[clap(long, default_value = None)]
+        due_on: Option<DueDate>,
+    },
+
+    /// Edit milestone
+    Edit {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Milestone title or number (optional, picked from milestones list if not set)
+        #[clap(long, short, default_value = None)]
+        milestone: Option<MilestoneRef>,
+        /// New milestone title (optional)
+        #[clap(long, short, default_value = None)]
+        title: Option<String>,
+        /// New milestone description (optional)
+        #[clap(long, short, default_value = None)]
+        description: Option<String>,
+        /// New due date in format `YYYY-MM-DD` (optional)
+        #[clap(long, default_value = None)]
+        due_on: Option<DueDate>,
+        /// New state. Can be either `open` or `closed` (optional)
+        #[clap(long, short, default_value = None)]
+        state: Option<States>,
+    },
+
+    /// Close milestone
+    Close {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Milestone title or number (optional, picked from milestones list if not set)
+        #[clap(long, short, default_value = None)]
+        milestone: Option<MilestoneRef>,
+    },
+
+    /// Delete milestone
+    Delete {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Milestone title or number (optional, picked from milestones list if not set)
+        #[clap(long, short, default_value = None)]
+        milestone: Option<MilestoneRef>,
+    },
+}
@@ -1,6 +1,13 @@
+pub mod branch_command;
 pub mod comment_command;
 pub mod issue_command;
+pub mod label_command;
+pub mod milestone_command;
+pub mod notification_command;
+pub mod reaction_command;
 pub mod read_cli;
 pub mod release_command;
 pub mod repo_command;
+pub mod search_command;
 pub mod set_vars;
+pub mod tui_command;
@@ -0,0 +1,53 @@
+use clap::Subcommand;
+
+use crate::cli_in::set_vars::NotificationReason;
+use crate::git_utils::repo_info::RepoInfo;
+
+#[derive(Subcommand)]
+pub enum NotificationCommand {
+    /// Get list of unread notifications
+    List {
+        /// Only notifications of repo. Format: `owner/repo` (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoInfo>,
+        /// Only notifications with reason. Example: `mention`, `review_requested`, `assign` (optional)
+        #[clap(long = "reason", short = 'R', default_value = None)]
+        reason: Option<NotificationReason>,
+        /// Only notifications where you are directly participating or mentioned (optional)
+        #[clap(long, short)]
+        participating: bool,
+        /// Show read notifications too (optional)
+        #[clap(long, short)]
+        all: bool,
+        /// Keep running and print new notifications as they arrive (optional)
+        #[clap(long, short)]
+        watch: bool,
+    },
+
+    /// Mark notifications as read
+    Read {
+        /// Notification thread id (optional, picked from unread notifications if not set)
+        #[clap(long, short, default_value = None, conflicts_with = "all")]
+        id: Option<i64>,
+        /// Mark all notifications as read (optional)
+        #[clap(long, short)]
+        all: bool,
+        /// With `--all` mark only notifications of repo. Format: `owner/repo` (optional)
+        #[clap(long, short, default_value = None, requires = "all")]
+        repo: Option<RepoInfo>,
+    },
+
+    /// Mute notification thread until you are mentioned again
+    Mute {
+        /// Notification thread id (optional, picked from unread notifications if not set)
+        #[clap(long, short, default_value = None)]
+        id: Option<i64>,
+    },
+
+    /// Show issue or pull request of notification and mark it as read
+    Open {
+        /// Notification thread id (optional, picked from unread notifications if not set)
+        #[clap(long, short, default_value = None)]
+        id: Option<i64>,
+    },
+}
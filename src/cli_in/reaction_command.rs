@@ -0,0 +1,61 @@
+use clap::Subcommand;
+
+use crate::cli_in::set_vars::{ReactionTarget, Reactions};
+use crate::git_utils::repo_info::{RepoName, RepoOwner};
+
+#[derive(Subcommand)]
+pub enum ReactionCommand {
+    /// Get list of reactions
+    List {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// What has reactions. Can be either `issue` (also pull request), `comment` or `review-comment` (optional)
+        #[clap(long, short, default_value = "issue")]
+        target: ReactionTarget,
+        /// Issue/pull request number or comment id
+        #[clap(long, short)]
+        id: i64,
+    },
+
+    /// Add reaction
+    Add {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// What gets reaction. Can be either `issue` (also pull request), `comment` or `review-comment` (optional)
+        #[clap(long, short, default_value = "issue")]
+        target: ReactionTarget,
+        /// Issue/pull request number or comment id
+        #[clap(long, short)]
+        id: i64,
+        /// Reaction. Can be `+1`, `-1`, `laugh`, `hooray`, `confused`, `heart`, `rocket` or `eyes`
+        #[clap(long, short, allow_hyphen_values = true)]
+        content: Reactions,
+    },
+
+    /// Remove your reaction
+    Remove {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// What has reaction. Can be either `issue` (also pull request), `comment` or `review-comment` (optional)
+        #[clap(long, short, default_value = "issue")]
+        target: ReactionTarget,
+        /// Issue/pull request number or comment id
+        #[clap(long, short)]
+        id: i64,
+        /// Reaction. Can be `+1`, `-1`, `laugh`, `hooray`, `confused`, `heart`, `rocket` or `eyes`
+        #[clap(long, short, allow_hyphen_values = true)]
+        content: Reactions,
+    },
+}
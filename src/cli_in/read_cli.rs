@@ -1,7 +1,14 @@
+use crate::cli_in::branch_command::BranchCommand;
 use crate::cli_in::comment_command::CommentCommand;
 use crate::cli_in::issue_command::IssueCommand;
+use crate::cli_in::label_command::LabelCommand;
+use crate::cli_in::milestone_command::MilestoneCommand;
+use crate::cli_in::notification_command::NotificationCommand;
+use crate::cli_in::reaction_command::ReactionCommand;
 use crate::cli_in::release_command::ReleaseCommand;
 use crate::cli_in::repo_command::RepoCommand;
+use crate::cli_in::search_command::SearchCommand;
+use crate::cli_in::tui_command::TuiCommand;
 use clap::{Parser, Subcommand};
 
 #[derive(Parser)]
@@ -25,6 +32,30 @@ pub enum CliCommand {
         subcommand: CommentCommand,
     },
 
+    /// Work with labels
+    Label {
+        #[command(subcommand)]
+        subcommand: LabelCommand,
+    },
+
+    /// Work with milestones
+    Milestone {
+        #[command(subcommand)]
+        subcommand: MilestoneCommand,
+    },
+
+    /// Work with notifications
+    Notification {
+        #[command(subcommand)]
+        subcommand: NotificationCommand,
+    },
+
+    /// Work with reactions
+    Reaction {
+        #[command(subcommand)]
+        subcommand: ReactionCommand,
+    },
+
     /// Work with repos
     Repo {
         #[command(subcommand)]
@@ -36,4 +67,29 @@ pub enum CliCommand {
         #[command(subcommand)]
         subcommand: ReleaseCommand,
     },
+
+    /// Work with branches
+    Branch {
+        #[command(subcommand)]
+        subcommand: BranchCommand,
+    },
+
+    /// Search issues, pull requests, code, repos and users on github
+    Search {
+        #[command(subcommand)]
+        subcommand: SearchCommand,
+    },
+
+    /// Show what needs your attention: assigned issues, review requests, your pull requests and mentions
+    Status {
+        /// Max number of items in each section (optional)
+        #[clap(long, short, default_value = "10", value_parser = clap::value_parser!(i64).range(1..=100))]
+        limit: i64,
+    },
+
+    /// Work in terminal ui
+    Tui {
+        #[command(subcommand)]
+        subcommand: TuiCommand,
+    },
 }
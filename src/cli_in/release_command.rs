@@ -1,22 +1,28 @@
+use std::path::PathBuf;
+
 use clap::Subcommand;
 
+use crate::cli_in::set_vars::{BumpLevel, MakeLatest};
 use crate::git_utils::repo_info::{RepoName, RepoOwner};
 
 #[derive(Subcommand)]
 pub enum ReleaseCommand {
-    /// Create new release
+    /// Create new release. Refuses if tag already exists or work tree of current repo is dirty
     Create {
         /// Repo owner (optional)
-        #[clap(long, short)]
-        owner: RepoOwner,
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
         /// Repo name (optional)
-        #[clap(long, short)]
-        repo: RepoName,
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
         /// Tag name
-        #[clap(long)]
-        tag_name: String,
-        /// Target commit hash (only long variant of commit hash) (optional)
-        #[clap(long, default_value = "")]
+        #[clap(long, required_unless_present = "from_manifest")]
+        tag_name: Option<String>,
+        /// Make tag name `v<version>` from version in Cargo.toml, package.json or pyproject.toml (optional)
+        #[clap(long, conflicts_with = "tag_name")]
+        from_manifest: bool,
+        /// Target commit hash (only long variant of commit hash) or `HEAD` for local HEAD (optional)
+        #[clap(long, alias = "target", default_value = "")]
         target_commitish: String,
         /// Release name
         #[clap(long, short)]
@@ -33,6 +39,58 @@ pub enum ReleaseCommand {
         /// It's prerelease? (optional)
         #[clap(long, default_value = None)]
         prerelease: Option<bool>,
+        /// Add release notes generated by github to body (optional)
+        #[clap(long)]
+        generate_notes: bool,
+        /// Tag to generate notes from, by default it's previous release (optional)
+        #[clap(long, default_value = None, requires = "generate_notes")]
+        previous_tag: Option<String>,
+        /// Add changelog of changes since this tag to body (optional)
+        #[clap(long, default_value = None, conflicts_with = "generate_notes")]
+        changelog_from: Option<String>,
+    },
+
+    /// Make markdown changelog of merged pull requests and commits between two tags.
+    /// Entries are grouped by labels or Conventional Commits type (feat, fix, chore, ...)
+    Changelog {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Tag (or any ref) to start from, its changes are not included
+        from: String,
+        /// Tag (or any ref) to end with, default branch if not set (optional)
+        to: Option<String>,
+        /// Changelog title, `to` if not set (optional)
+        #[clap(long, short, default_value = None)]
+        title: Option<String>,
+        /// Add changelog to the top of file instead of printing. Example: `CHANGELOG.md` (optional)
+        #[clap(long, default_value = None)]
+        output: Option<PathBuf>,
+    },
+
+    /// Tag default branch with next semver version after latest release and create release.
+    /// Prerelease of release is `<next patch>-<preid>.1`, next prerelease increments its number
+    Bump {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Part of version to bump. Can be either `major`, `minor`, `patch` or `prerelease`
+        level: BumpLevel,
+        /// Prerelease identifier (optional)
+        #[clap(long, default_value = "rc")]
+        preid: String,
+        /// Add release notes generated by github to body (optional)
+        #[clap(long)]
+        generate_notes: bool,
+        /// Only print new tag and release, don't create them (optional)
+        #[clap(long)]
+        dry_run: bool,
     },
 
     /// Get latest release
@@ -53,9 +111,9 @@ pub enum ReleaseCommand {
         /// Repo name (optional)
         #[clap(long, short, default_value = None)]
         repo: Option<RepoName>,
-        /// Release tag
-        #[clap(long, short)]
-        tag: String,
+        /// Release tag (optional, picked from releases list if not set)
+        #[clap(long, short, default_value = None)]
+        tag: Option<String>,
     },
 
     /// Get release by id
@@ -66,8 +124,123 @@ pub enum ReleaseCommand {
         /// Repo name (optional)
         #[clap(long, short, default_value = None)]
         repo: Option<RepoName>,
-        /// Release id
-        #[clap(long, short)]
-        id: i64,
+        /// Release id (optional, picked from releases list if not set)
+        #[clap(long, short, default_value = None)]
+        id: Option<i64>,
+    },
+
+    /// Get list of releases, drafts and prereleases are marked
+    List {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Page number of the results to fetch (optional)
+        #[clap(long, short, default_value = "1")]
+        numb_of_page: i64,
+        /// Results on page (max 100) (optional)
+        #[clap(long, default_value = "30", value_parser = clap::value_parser!(i64).range(1..=100))]
+        rel_on_page: i64,
+    },
+
+    /// Get release from releases list
+    GetFromList {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+    },
+
+    /// Edit release. Not set fields are not changed
+    Edit {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Release id (optional, picked from releases list if not set)
+        #[clap(long, short, default_value = None)]
+        id: Option<i64>,
+        /// New release name (optional)
+        #[clap(long, short, default_value = None)]
+        name: Option<String>,
+        /// New release body (optional)
+        #[clap(long, short, default_value = None)]
+        body: Option<String>,
+        /// It's draft? (optional)
+        #[clap(long, default_value = None)]
+        draft: Option<bool>,
+        /// Publish draft release, same as `--draft false` (optional)
+        #[clap(long, conflicts_with = "draft")]
+        publish: bool,
+        /// It's prerelease? (optional)
+        #[clap(long, default_value = None)]
+        prerelease: Option<bool>,
+        /// Make release latest. Can be either `true`, `false` or `legacy` (by date and semver) (optional)
+        #[clap(long, default_value = None)]
+        make_latest: Option<MakeLatest>,
+    },
+
+    /// Delete release
+    Delete {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Release id (optional, picked from releases list if not set)
+        #[clap(long, short, default_value = None)]
+        id: Option<i64>,
+        /// Delete tag of release too (optional)
+        #[clap(long)]
+        cleanup_tag: bool,
+    },
+
+    /// Upload files as release assets
+    Upload {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Release tag
+        tag: String,
+        /// Files to upload, asset is named like file
+        #[clap(required = true)]
+        files: Vec<PathBuf>,
+        /// Replace assets with the same names (optional)
+        #[clap(long)]
+        clobber: bool,
+        /// Add sha256 of files to SHA256SUMS asset (optional)
+        #[clap(long)]
+        checksums: bool,
+    },
+
+    /// Download release assets
+    Download {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Release tag
+        tag: String,
+        /// Download only assets with names matching glob. Example: `*.tar.gz` (optional)
+        #[clap(long, short, default_value = "*")]
+        pattern: String,
+        /// Dir to save assets (optional)
+        #[clap(long, short, default_value = ".")]
+        dir: PathBuf,
+        /// Verify assets by SHA256SUMS asset (optional)
+        #[clap(long)]
+        verify: bool,
     },
 }
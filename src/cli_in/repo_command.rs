@@ -1,10 +1,13 @@
+use std::path::PathBuf;
+
 use clap::Subcommand;
 
 use crate::{
     cli_in::set_vars::{
-        Orders, ReposListOrgSorts, ReposListOrgTypes, ReposListUserTypes, Visibilities,
+        CommaList, GitProtocol, Orders, ReposListOrgSorts, ReposListOrgTypes, ReposListUserTypes,
+        Visibilities,
     },
-    git_utils::repo_info::{RepoName, RepoOwner},
+    git_utils::repo_info::{RepoInfo, RepoName, RepoOwner},
 };
 
 #[derive(Subcommand)]
@@ -163,4 +166,249 @@ pub enum RepoCommand {
         // #[clap(long, short, verbatim_doc_comment, default_value = "")]
         // affiliation: String,
     },
+
+    /// Show repo description, topics, default branch, visibility, stars, open issues, license
+    /// and latest release
+    View {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+    },
+
+    /// Edit repo settings. Not set fields are not changed
+    Edit {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        #[clap(long, default_value = None)]
+        allow_auto_merge: Option<bool>,
+        #[clap(long, default_value = None)]
+        allow_merge_commit: Option<bool>,
+        #[clap(long, default_value = None)]
+        allow_rebase_merge: Option<bool>,
+        #[clap(long, default_value = None)]
+        allow_squash_merge: Option<bool>,
+        #[clap(long, default_value = None)]
+        delete_branch_on_merge: Option<bool>,
+        #[clap(long, default_value = None)]
+        has_issues: Option<bool>,
+        #[clap(long, default_value = None)]
+        has_projects: Option<bool>,
+        #[clap(long, default_value = None)]
+        has_wiki: Option<bool>,
+        #[clap(long, default_value = None)]
+        is_template: Option<bool>,
+        #[clap(long, default_value = None)]
+        private: Option<bool>,
+        /// New description, empty string clears it (optional)
+        #[clap(long, default_value = None)]
+        description: Option<String>,
+        /// New homepage, empty string clears it (optional)
+        #[clap(long, default_value = None)]
+        homepage: Option<String>,
+        /// New default branch, it must exist (optional)
+        #[clap(long, default_value = None)]
+        default_branch: Option<String>,
+        /// Replace topics with comma separated list, empty string removes all topics (optional)
+        #[clap(long, default_value = None)]
+        topics: Option<CommaList>,
+    },
+
+    /// Rename repo
+    Rename {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// New repo name
+        new_name: RepoName,
+    },
+
+    /// Archive repo, it becomes read-only
+    Archive {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+    },
+
+    /// Unarchive repo
+    Unarchive {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+    },
+
+    /// Delete repo. Full repo name must be typed to confirm
+    Delete {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Delete without confirmation (optional)
+        #[clap(long, short)]
+        yes: bool,
+    },
+
+    /// Clone repo. For forks parent repo is added as `upstream` remote
+    Clone {
+        /// Repo in format `owner/repo` (optional, picked from your repos list if not set)
+        repo: Option<RepoInfo>,
+        /// Dir to clone in, repo name if not set (optional)
+        dir: Option<PathBuf>,
+        /// Clone by 'ssh' or 'https' (optional)
+        #[clap(long, env = "GITHUB_CLI_PROTOCOL", default_value = "https")]
+        protocol: GitProtocol,
+    },
+
+    /// Clone all org repos or fast-forward already cloned ones
+    CloneAll {
+        /// Org name
+        #[clap(long)]
+        org: String,
+        /// Only repos with names matching glob. Example: `*-service` (optional)
+        #[clap(long, short, default_value = "*")]
+        filter: String,
+        /// Number of repos cloned at the same time (optional)
+        #[clap(long, short, default_value = "4", value_parser = clap::value_parser!(u32).range(1..=32))]
+        jobs: u32,
+        /// Dir to clone repos in (optional)
+        #[clap(long, short, default_value = ".")]
+        dir: PathBuf,
+        /// Clone by 'ssh' or 'https' (optional)
+        #[clap(long, env = "GITHUB_CLI_PROTOCOL", default_value = "https")]
+        protocol: GitProtocol,
+    },
+
+    /// Sync fork branch with upstream repo
+    Sync {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Branch to sync, default branch if not set (optional)
+        #[clap(long, short, default_value = None)]
+        branch: Option<String>,
+        /// Fast-forward local branch in current dir after sync, origin of current dir must be the fork (optional)
+        #[clap(long)]
+        fast_forward: bool,
+    },
+
+    /// Branch protection as code
+    Protection {
+        #[command(subcommand)]
+        subcommand: ProtectionCommand,
+    },
+
+    /// Repo rulesets as code
+    Ruleset {
+        #[command(subcommand)]
+        subcommand: RulesetCommand,
+    },
+
+    /// Show changes needed to bring org repos to manifest
+    Plan {
+        /// Yaml manifest with `org` and `repos` (name, settings, `topics`, `teams`, `labels`)
+        #[clap(long, short)]
+        file: PathBuf,
+        /// Archive org repos which are not in manifest (optional)
+        #[clap(long)]
+        prune: bool,
+    },
+
+    /// Create and update org repos to match manifest. Changes are shown before applying
+    Apply {
+        /// Yaml manifest with `org` and `repos` (name, settings, `topics`, `teams`, `labels`)
+        #[clap(long, short)]
+        file: PathBuf,
+        /// Archive org repos which are not in manifest (optional)
+        #[clap(long)]
+        prune: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ProtectionCommand {
+    /// Print branch protection as yaml, it can be used as file for `set`
+    Get {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Branch name, default branch if not set (optional)
+        #[clap(long, short, default_value = None)]
+        branch: Option<String>,
+    },
+
+    /// Set branch protection from yaml file. Changes are shown before applying
+    Set {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Branch name, default branch if not set (optional)
+        #[clap(long, short, default_value = None)]
+        branch: Option<String>,
+        /// Yaml file with `required_reviews`, `status_checks`, `enforce_admins`, `linear_history`,
+        /// `signed_commits`, `allow_force_pushes`, `allow_deletions` and `restrictions`
+        #[clap(long, short)]
+        file: PathBuf,
+        /// Only show changes (optional)
+        #[clap(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum RulesetCommand {
+    /// Get list of repo rulesets
+    List {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+    },
+
+    /// Apply rulesets from yaml file. Rulesets are matched by name, changes are shown before applying
+    Apply {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Yaml file with list of rulesets in github api format (`name`, `target`, `enforcement`,
+        /// `conditions`, `rules`, `bypass_actors`)
+        #[clap(long, short)]
+        file: PathBuf,
+        /// Delete rulesets which are not in file (optional)
+        #[clap(long)]
+        prune: bool,
+        /// Only show changes (optional)
+        #[clap(long)]
+        dry_run: bool,
+    },
 }
@@ -0,0 +1,148 @@
+use clap::Subcommand;
+
+use crate::cli_in::set_vars::{
+    CommaList, Orders, SearchIssuesSorts, SearchReposSorts, SearchUsersSorts,
+};
+use crate::git_utils::repo_info::RepoInfo;
+
+#[derive(Subcommand)]
+pub enum SearchCommand {
+    /// Search issues in all repos
+    Issues {
+        /// Search words and qualifiers. Example: `crash is:open label:bug` (optional)
+        query: Vec<String>,
+        /// Search only in repo. Format: `owner/repo` (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoInfo>,
+        /// Search only in repos of organization (optional)
+        #[clap(long, default_value = "")]
+        org: String,
+        /// The user that created the issues (optional)
+        #[clap(long, short, default_value = "")]
+        author: String,
+        /// The user assigned to the issues (optional)
+        #[clap(long, default_value = "")]
+        assignee: String,
+        /// A list of comma separated label names, all must be on issue. Example: `bug,ui` (optional)
+        #[clap(long, short, default_value = "")]
+        label: CommaList,
+        /// A list of comma separated states. Example: `open`, `closed,locked` (optional)
+        #[clap(long, short, default_value = "")]
+        is: CommaList,
+        /// Sort by. Can be either `best-match`, `comments`, `created`, `updated`, `reactions`, `reactions-+1` or `interactions` (optional)
+        #[clap(long, short, default_value = "best-match", allow_hyphen_values = true)]
+        sort: SearchIssuesSorts,
+        /// Can be either `asc` or `desc`. Ignored with `best-match` sort (optional)
+        #[clap(long, default_value = "desc")]
+        order: Orders,
+        /// Max number of results, search gives not more than 1000 (optional)
+        #[clap(long, default_value = "30", value_parser = clap::value_parser!(i64).range(1..=1000))]
+        limit: i64,
+    },
+
+    /// Search pull requests in all repos
+    Prs {
+        /// Search words and qualifiers. Example: `fix is:merged review:approved` (optional)
+        query: Vec<String>,
+        /// Search only in repo. Format: `owner/repo` (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoInfo>,
+        /// Search only in repos of organization (optional)
+        #[clap(long, default_value = "")]
+        org: String,
+        /// The user that created the pull requests (optional)
+        #[clap(long, short, default_value = "")]
+        author: String,
+        /// The user assigned to the pull requests (optional)
+        #[clap(long, default_value = "")]
+        assignee: String,
+        /// A list of comma separated label names, all must be on pull request. Example: `bug,ui` (optional)
+        #[clap(long, short, default_value = "")]
+        label: CommaList,
+        /// A list of comma separated states. Example: `open`, `merged`, `closed,unmerged` (optional)
+        #[clap(long, short, default_value = "")]
+        is: CommaList,
+        /// Sort by. Can be either `best-match`, `comments`, `created`, `updated`, `reactions`, `reactions-+1` or `interactions` (optional)
+        #[clap(long, short, default_value = "best-match", allow_hyphen_values = true)]
+        sort: SearchIssuesSorts,
+        /// Can be either `asc` or `desc`. Ignored with `best-match` sort (optional)
+        #[clap(long, default_value = "desc")]
+        order: Orders,
+        /// Max number of results, search gives not more than 1000 (optional)
+        #[clap(long, default_value = "30", value_parser = clap::value_parser!(i64).range(1..=1000))]
+        limit: i64,
+    },
+
+    /// Search code in default branches of repos
+    Code {
+        /// Search words and qualifiers, at least one word is needed. Example: `fn main path:src` (required)
+        #[clap(required = true)]
+        query: Vec<String>,
+        /// Search only in repo. Format: `owner/repo` (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoInfo>,
+        /// Search only in repos of organization (optional)
+        #[clap(long, default_value = "")]
+        org: String,
+        /// Language of files. Example: `rust` (optional)
+        #[clap(long, default_value = "")]
+        language: String,
+        /// Extension of files. Example: `toml` (optional)
+        #[clap(long, short, default_value = "")]
+        extension: String,
+        /// Max number of results, search gives not more than 1000 (optional)
+        #[clap(long, default_value = "30", value_parser = clap::value_parser!(i64).range(1..=1000))]
+        limit: i64,
+    },
+
+    /// Search repos
+    Repos {
+        /// Search words and qualifiers. Example: `cli stars:>100` (optional)
+        query: Vec<String>,
+        /// Search only in repos of user (optional)
+        #[clap(long, short, default_value = "")]
+        user: String,
+        /// Search only in repos of organization (optional)
+        #[clap(long, default_value = "")]
+        org: String,
+        /// Main language of repo. Example: `rust` (optional)
+        #[clap(long, default_value = "")]
+        language: String,
+        /// A list of comma separated topics, all must be in repo. Example: `cli,github` (optional)
+        #[clap(long, short, default_value = "")]
+        topic: CommaList,
+        /// Sort by. Can be either `best-match`, `stars`, `forks`, `help-wanted-issues` or `updated` (optional)
+        #[clap(long, short, default_value = "best-match")]
+        sort: SearchReposSorts,
+        /// Can be either `asc` or `desc`. Ignored with `best-match` sort (optional)
+        #[clap(long, default_value = "desc")]
+        order: Orders,
+        /// Max number of results, search gives not more than 1000 (optional)
+        #[clap(long, default_value = "30", value_parser = clap::value_parser!(i64).range(1..=1000))]
+        limit: i64,
+    },
+
+    /// Search users and organizations
+    Users {
+        /// Search words and qualifiers. Example: `john followers:>10` (optional)
+        query: Vec<String>,
+        /// Account type. Can be either `user` or `org` (optional)
+        #[clap(long = "type", short = 't', default_value = "")]
+        account_type: String,
+        /// Location in profile. Example: `berlin` (optional)
+        #[clap(long, default_value = "")]
+        location: String,
+        /// Language of user repos. Example: `rust` (optional)
+        #[clap(long, default_value = "")]
+        language: String,
+        /// Sort by. Can be either `best-match`, `followers`, `repositories` or `joined` (optional)
+        #[clap(long, short, default_value = "best-match")]
+        sort: SearchUsersSorts,
+        /// Can be either `asc` or `desc`. Ignored with `best-match` sort (optional)
+        #[clap(long, default_value = "desc")]
+        order: Orders,
+        /// Max number of results, search gives not more than 1000 (optional)
+        #[clap(long, default_value = "30", value_parser = clap::value_parser!(i64).range(1..=1000))]
+        limit: i64,
+    },
+}
@@ -1,8 +1,10 @@
 use std::{fmt::Display, str::FromStr};
 
+use chrono::{DateTime, NaiveDate, Utc};
 use octorust::types::{
-    IssuesListState, Order, ReposCreateInOrgRequestVisibility, ReposListOrgSort, ReposListOrgType,
-    ReposListUserType, Sort, State,
+    Content, IssuesListState, LockReason, Order, ReposCreateInOrgRequestVisibility,
+    ReposListOrgSort, ReposListOrgType, ReposListUserType, SearchIssuesPullRequestsSort,
+    SearchReposSort, SearchUsersSort, Sort, State,
 };
 
 #[derive(Debug, Clone)]
@@ -156,6 +158,15 @@ impl FromStr for CommentTarget {
     }
 }
 
+impl Display for CommentTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommentTarget::Issue => write!(f, "issue"),
+            CommentTarget::PullRequest => write!(f, "pull-request"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Sorts(pub Sort);
 
@@ -170,3 +181,326 @@ impl FromStr for Sorts {
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelColor(pub String);
+
+impl FromStr for LabelColor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let color = s.trim().trim_start_matches('#').to_lowercase();
+        if color.len() == 6 && color.chars().all(|c| c.is_ascii_hexdigit()) {
+            Ok(Self(color))
+        } else {
+            Err("Bad input. Label color must be hex like 'd73a4a' or '#d73a4a'".to_string())
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueDate(pub DateTime<Utc>);
+
+impl FromStr for DueDate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d") {
+            // Noon in UTC keeps the same date in most timezones
+            Ok(date) => Ok(Self(date.and_hms_opt(12, 0, 0).unwrap().and_utc())),
+            Err(_) => Err("Bad input. Due date must be in format 'YYYY-MM-DD'".to_string()),
+        }
+    }
+}
+
+/// Milestone set by number or by title
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MilestoneRef {
+    Number(i64),
+    Title(String),
+}
+
+impl FromStr for MilestoneRef {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            Err("Milestone cannot be empty".to_string())
+        } else if let Ok(number) = s.parse::<i64>() {
+            Ok(Self::Number(number))
+        } else {
+            Ok(Self::Title(s.to_string()))
+        }
+    }
+}
+
+/// Comma separated list. Empty input gives empty list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommaList(pub Vec<String>);
+
+impl FromStr for CommaList {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(
+            s.split(',')
+                .map(|item| item.trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect(),
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CloseReason {
+    Completed,
+    NotPlanned,
+    Duplicate,
+}
+
+impl FromStr for CloseReason {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "completed" => Ok(CloseReason::Completed),
+            "not_planned" => Ok(CloseReason::NotPlanned),
+            "duplicate" => Ok(CloseReason::Duplicate),
+            _ => Err(
+                "Bad input. Reason can be only 'completed', 'not_planned' or 'duplicate'"
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+impl Display for CloseReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CloseReason::Completed => write!(f, "completed"),
+            CloseReason::NotPlanned => write!(f, "not_planned"),
+            CloseReason::Duplicate => write!(f, "duplicate"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LockReasons(pub LockReason);
+
+impl FromStr for LockReasons {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off-topic" => Ok(Self(LockReason::OffTopic)),
+            "too heated" | "too-heated" => Ok(Self(LockReason::TooHeated)),
+            "resolved" => Ok(Self(LockReason::Resolved)),
+            "spam" => Ok(Self(LockReason::Spam)),
+            _ => Err(
+                "Bad input. Lock reason can be only 'off-topic', 'too heated', 'resolved' or 'spam'"
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Reactions(pub Content);
+
+impl FromStr for Reactions {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+1" => Ok(Self(Content::PlusOne)),
+            "-1" => Ok(Self(Content::MinusOne)),
+            "laugh" => Ok(Self(Content::Laugh)),
+            "hooray" => Ok(Self(Content::Hooray)),
+            "confused" => Ok(Self(Content::Confused)),
+            "heart" => Ok(Self(Content::Heart)),
+            "rocket" => Ok(Self(Content::Rocket)),
+            "eyes" => Ok(Self(Content::Eyes)),
+            _ => Err("Bad input. Reaction can be only '+1', '-1', 'laugh', 'hooray', 'confused', 'heart', 'rocket' or 'eyes'".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReactionTarget {
+    Issue,
+    Comment,
+    ReviewComment,
+}
+
+impl FromStr for ReactionTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "issue" => Ok(ReactionTarget::Issue),
+            "comment" => Ok(ReactionTarget::Comment),
+            "review-comment" => Ok(ReactionTarget::ReviewComment),
+            _ => Err(
+                "Bad input. Reaction target can be only 'issue', 'comment' or 'review-comment'"
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IssuesListSorts {
+    Created,
+    Updated,
+    Comments,
+    Reactions,
+}
+
+impl FromStr for IssuesListSorts {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "created" => Ok(IssuesListSorts::Created),
+            "updated" => Ok(IssuesListSorts::Updated),
+            "comments" => Ok(IssuesListSorts::Comments),
+            "reactions" => Ok(IssuesListSorts::Reactions),
+            _ => Err(
+                "Bad input. Sort can be only 'created', 'updated', 'comments' or 'reactions'"
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchIssuesSorts(pub SearchIssuesPullRequestsSort);
+
+impl FromStr for SearchIssuesSorts {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "best-match" => Ok(Self(SearchIssuesPullRequestsSort::Noop)),
+            "comments" => Ok(Self(SearchIssuesPullRequestsSort::Comments)),
+            "created" => Ok(Self(SearchIssuesPullRequestsSort::Created)),
+            "updated" => Ok(Self(SearchIssuesPullRequestsSort::Updated)),
+            "reactions" => Ok(Self(SearchIssuesPullRequestsSort::Reactions)),
+            "reactions-+1" => Ok(Self(SearchIssuesPullRequestsSort::ReactionsPlusOne)),
+            "interactions" => Ok(Self(SearchIssuesPullRequestsSort::Interactions)),
+            _ => Err("Bad input. Sort can be only 'best-match', 'comments', 'created', 'updated', 'reactions', 'reactions-+1' or 'interactions'".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchReposSorts(pub SearchReposSort);
+
+impl FromStr for SearchReposSorts {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "best-match" => Ok(Self(SearchReposSort::Noop)),
+            "stars" => Ok(Self(SearchReposSort::Stars)),
+            "forks" => Ok(Self(SearchReposSort::Forks)),
+            "help-wanted-issues" => Ok(Self(SearchReposSort::HelpWantedIssues)),
+            "updated" => Ok(Self(SearchReposSort::Updated)),
+            _ => Err("Bad input. Sort can be only 'best-match', 'stars', 'forks', 'help-wanted-issues' or 'updated'".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchUsersSorts(pub SearchUsersSort);
+
+impl FromStr for SearchUsersSorts {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "best-match" => Ok(Self(SearchUsersSort::Noop)),
+            "followers" => Ok(Self(SearchUsersSort::Followers)),
+            "repositories" => Ok(Self(SearchUsersSort::Repositories)),
+            "joined" => Ok(Self(SearchUsersSort::Joined)),
+            _ => Err(
+                "Bad input. Sort can be only 'best-match', 'followers', 'repositories' or 'joined'"
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationReason(pub String);
+
+impl FromStr for NotificationReason {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "assign" | "author" | "comment" | "ci_activity" | "invitation" | "manual"
+            | "mention" | "review_requested" | "security_alert" | "state_change" | "subscribed"
+            | "team_mention" => Ok(Self(s.to_string())),
+            _ => Err("Bad input. Reason can be only 'assign', 'author', 'comment', 'ci_activity', 'invitation', 'manual', 'mention', 'review_requested', 'security_alert', 'state_change', 'subscribed' or 'team_mention'".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MakeLatest(pub String);
+
+impl FromStr for MakeLatest {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "true" | "false" | "legacy" => Ok(Self(s.to_string())),
+            _ => Err("Bad input. Make latest can be only 'true', 'false' or 'legacy'".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BumpLevel {
+    Major,
+    Minor,
+    Patch,
+    Prerelease,
+}
+
+impl FromStr for BumpLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "major" => Ok(BumpLevel::Major),
+            "minor" => Ok(BumpLevel::Minor),
+            "patch" => Ok(BumpLevel::Patch),
+            "prerelease" => Ok(BumpLevel::Prerelease),
+            _ => Err(
+                "Bad input. Level can be only 'major', 'minor', 'patch' or 'prerelease'"
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitProtocol {
+    Ssh,
+    Https,
+}
+
+impl FromStr for GitProtocol {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ssh" => Ok(GitProtocol::Ssh),
+            "https" => Ok(GitProtocol::Https),
+            _ => Err("Bad input. Protocol can be only 'ssh' or 'https'".to_string()),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use clap::Subcommand;
+
+use crate::cli_in::set_vars::{GitProtocol, IssuesListStates};
+use crate::git_utils::repo_info::{RepoName, RepoOwner};
+
+#[derive(Subcommand)]
+pub enum TuiCommand {
+    /// Browse and triage issues
+    Issues {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// The user that created the issues (optional)
+        #[clap(long, short, default_value = "")]
+        creator: String,
+        /// Can be the name of a user. Pass in `none` for issues with no assigned user, and `*` for issues assigned to any user (optional)
+        #[clap(long, short, default_value = "")]
+        assignee: String,
+        /// Indicates the state of the issues to return. Can be either `open`, `closed`, or `all` (optional)
+        #[clap(long, short, default_value = "open")]
+        state: IssuesListStates,
+        /// A list of comma separated label names. Example: `bug,ui,@high` (optional)
+        #[clap(long, short, default_value = "")]
+        labels: String,
+        /// Results on page (max 100) (optional)
+        #[clap(long, short, default_value = "30", value_parser = clap::value_parser!(i64).range(1..=100))]
+        iss_on_page: i64,
+    },
+
+    /// Review pull requests
+    Prs {
+        /// Repo owner (optional)
+        #[clap(long, short, default_value = None)]
+        owner: Option<RepoOwner>,
+        /// Repo name (optional)
+        #[clap(long, short, default_value = None)]
+        repo: Option<RepoName>,
+        /// Indicates the state of the pull requests to return. Can be either `open`, `closed`, or `all` (optional)
+        #[clap(long, short, default_value = "open")]
+        state: IssuesListStates,
+        /// Results on page (max 100) (optional)
+        #[clap(long, short, default_value = "30", value_parser = clap::value_parser!(i64).range(1..=100))]
+        prs_on_page: i64,
+    },
+
+    /// Dashboard of repos from org or user
+    Repos {
+        /// Org name
+        #[clap(long, default_value = None, required_unless_present = "user", conflicts_with = "user")]
+        org: Option<String>,
+        /// User name
+        #[clap(long, default_value = None)]
+        user: Option<String>,
+        /// Clone by 'ssh' or 'https' (optional)
+        #[clap(long, env = "GITHUB_CLI_PROTOCOL", default_value = "https")]
+        protocol: GitProtocol,
+    },
+}
@@ -1,37 +1,345 @@
 use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io, process};
 
 use fzf_wrapped::{run_with_output, Border, Fzf};
-use octorust::types::IssueSimple;
+use octorust::types::{
+    IssueComment, IssueSimple, Label, Milestone, MinimalRepository, PullRequestSimple, Release,
+    ShortBranch, Thread,
+};
 
-pub fn choose_issue(list_issues: Vec<IssueSimple>) -> Result<Option<IssueSimple>, Box<dyn Error>> {
-    let issue_titles: Vec<String> = list_issues
+use crate::cli_in::set_vars::CommentTarget;
+use crate::cli_out::print_in_cli::{progress_bar, release_marks};
+use crate::git_utils::notifications;
+use crate::git_utils::repo_info::RepoInfo;
+
+/// Item which can be picked from fzf list
+pub trait Pickable {
+    /// Name of items in list (used in fzf border label and header)
+    const LIST_NAME: &'static str;
+
+    /// Line shown in fzf list
+    fn line(&self) -> String;
+
+    /// Rendered item shown in fzf preview pane
+    fn preview(&self) -> String;
+
+    /// Issue/pull request number whose comments are shown in preview pane under the item
+    fn comments_source(&self) -> Option<(i64, CommentTarget)> {
+        None
+    }
+
+    /// Repo of comments in preview pane, if item has its own (otherwise repo given to picker)
+    fn comments_repo(&self) -> Option<RepoInfo> {
+        None
+    }
+}
+
+/// Pick one item from list. Return None if nothing was picked
+pub fn choose_one<T: Pickable>(
+    list: Vec<T>,
+    repo_info: Option<&RepoInfo>,
+) -> Result<Option<T>, Box<dyn Error>> {
+    let mut choosed = choose(list, repo_info, false)?;
+    Ok(choosed.pop())
+}
+
+/// Pick several items from list (marked by TAB). Return empty vec if nothing was picked
+pub fn choose_many<T: Pickable>(
+    list: Vec<T>,
+    repo_info: Option<&RepoInfo>,
+) -> Result<Vec<T>, Box<dyn Error>> {
+    choose(list, repo_info, true)
+}
+
+fn choose<T: Pickable>(
+    list: Vec<T>,
+    repo_info: Option<&RepoInfo>,
+    multi: bool,
+) -> Result<Vec<T>, Box<dyn Error>> {
+    let preview_dir = write_previews(&list)?;
+
+    // Every line is "index\tnumber\ttarget\towner\trepo\tline", but fzf shows only line.
+    // Comments are shown only if item has number and there is repo for it
+    let lines: Vec<String> = list
         .iter()
-        .map(|iss| format!("({}) Title: {}", iss.number, iss.title))
+        .enumerate()
+        .map(|(index, item)| {
+            let comments_repo = item.comments_repo().or_else(|| repo_info.cloned());
+            let (number, target, owner, name) = match (item.comments_source(), comments_repo) {
+                (Some((n, t)), Some(r)) => {
+                    (n.to_string(), t.to_string(), r.get_owner(), r.get_name())
+                }
+                _ => Default::default(),
+            };
+            format!(
+                "{index}\t{number}\t{target}\t{owner}\t{name}\t{}",
+                item.line().replace(['\n', '\t'], " ")
+            )
+        })
         .collect();
 
+    let mut fzf_args = vec![
+        "--delimiter=\t".to_string(),
+        "--with-nth=6..".to_string(),
+        format!("--preview={}", preview_command(&preview_dir.0)?),
+        "--preview-window=right,60%,wrap".to_string(),
+    ];
+    if multi {
+        fzf_args.push("--multi".to_string());
+    }
+
+    let header = if multi {
+        format!(
+            "Pick needed {} from list (TAB to mark several)",
+            T::LIST_NAME
+        )
+    } else {
+        format!("Pick needed {} from list", T::LIST_NAME)
+    };
+
     let fzf = Fzf::builder()
         .border(Border::Rounded)
-        .border_label("List issues")
-        .header("Pick needed issue form list")
+        .border_label(format!("List {}", T::LIST_NAME))
+        .header(header)
+        .custom_args(fzf_args)
         .build()?;
 
-    let choosed_issue = run_with_output(fzf, issue_titles);
+    let output = run_with_output(fzf, lines);
 
-    let issue_num = match choosed_issue {
-        Some(ch_i) => {
-            let index = match ch_i.find(')') {
-                Some(c) => c,
-                None => return Ok(None),
-            };
-            ch_i[1..index].parse::<i64>()
-        }
-        None => return Ok(None),
-    }?;
+    let choosed_indexes: Vec<usize> = match output {
+        Some(out) => out
+            .lines()
+            .filter_map(|line| line.split('\t').next()?.parse::<usize>().ok())
+            .collect(),
+        None => return Ok(Vec::new()),
+    };
+
+    Ok(list
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| choosed_indexes.contains(index))
+        .map(|(_, item)| item)
+        .collect())
+}
+
+/// Temp dir with previews, removed when picker returns (on errors too)
+struct PreviewDir(PathBuf);
+
+impl Drop for PreviewDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Write preview of every item in temp dir as file named by item index
+fn write_previews<T: Pickable>(list: &[T]) -> Result<PreviewDir, io::Error> {
+    let preview_dir =
+        PreviewDir(env::temp_dir().join(format!("github-cli-preview-{}", process::id())));
+    fs::create_dir_all(&preview_dir.0)?;
+
+    for (index, item) in list.iter().enumerate() {
+        fs::write(preview_dir.0.join(index.to_string()), item.preview())?;
+    }
+    Ok(preview_dir)
+}
+
+/// Shell command for fzf preview: show item and (if it has) comments through this binary
+fn preview_command(preview_dir: &Path) -> Result<String, io::Error> {
+    let github_cli = env::current_exe()?;
+
+    Ok(format!(
+        "cat '{}'/{{1}}; [ -n {{2}} ] && '{}' comment get-all --owner {{4}} --repo {{5}} --number {{2}} --target {{3}}",
+        preview_dir.display(),
+        github_cli.display(),
+    ))
+}
+
+impl Pickable for IssueSimple {
+    const LIST_NAME: &'static str = "issues";
+
+    fn line(&self) -> String {
+        format!("({}) Title: {}", self.number, self.title)
+    }
+
+    fn preview(&self) -> String {
+        let labels: Vec<&str> = self.labels.iter().map(|l| l.name.as_str()).collect();
+        format!(
+            " Issue {}: {}\n State: {}\n Labels: {}\n\n{}\n",
+            self.number,
+            self.title,
+            self.state,
+            labels.join(", "),
+            self.body
+        )
+    }
+
+    fn comments_source(&self) -> Option<(i64, CommentTarget)> {
+        Some((self.number, CommentTarget::Issue))
+    }
+}
+
+impl Pickable for PullRequestSimple {
+    const LIST_NAME: &'static str = "pull requests";
+
+    fn line(&self) -> String {
+        format!("({}) Title: {}", self.number, self.title)
+    }
+
+    fn preview(&self) -> String {
+        format!(
+            " Pull request {}: {}\n State: {}\n {} <- {}\n\n{}\n",
+            self.number, self.title, self.state, self.base.ref_, self.head.ref_, self.body
+        )
+    }
+
+    fn comments_source(&self) -> Option<(i64, CommentTarget)> {
+        Some((self.number, CommentTarget::PullRequest))
+    }
+}
+
+impl Pickable for MinimalRepository {
+    const LIST_NAME: &'static str = "repos";
+
+    fn line(&self) -> String {
+        format!("{} {}", self.full_name, self.description)
+    }
+
+    fn preview(&self) -> String {
+        format!(
+            " Repo: {}\n Language: {}\n Stars: {}\n Open issues: {}\n Url: {}\n\n{}\n",
+            self.full_name,
+            self.language,
+            self.stargazers_count,
+            self.open_issues_count,
+            self.html_url,
+            self.description
+        )
+    }
+}
+
+impl Pickable for Release {
+    const LIST_NAME: &'static str = "releases";
+
+    fn line(&self) -> String {
+        format!(
+            "({}) Tag: {} {}{}",
+            self.id,
+            self.tag_name,
+            self.name,
+            release_marks(self)
+        )
+    }
+
+    fn preview(&self) -> String {
+        format!(
+            " Release {}: {}\n Tag commit: {}\n\n{}\n",
+            self.tag_name, self.name, self.target_commitish, self.body
+        )
+    }
+}
+
+impl Pickable for IssueComment {
+    const LIST_NAME: &'static str = "comments";
+
+    fn line(&self) -> String {
+        let who = match &self.user {
+            Some(u) => u.login.as_str(),
+            None => "",
+        };
+        format!("({}) {}: {}", self.id, who, self.body)
+    }
+
+    fn preview(&self) -> String {
+        format!(
+            " Comment {}\n Url: {}\n\n{}\n",
+            self.id, self.html_url, self.body
+        )
+    }
+}
+
+impl Pickable for Label {
+    const LIST_NAME: &'static str = "labels";
+
+    fn line(&self) -> String {
+        format!("{}: {}", self.name, self.description)
+    }
+
+    fn preview(&self) -> String {
+        format!(
+            " Label: {}\n Color: #{}\n Description: {}\n",
+            self.name, self.color, self.description
+        )
+    }
+}
+
+impl Pickable for ShortBranch {
+    const LIST_NAME: &'static str = "branches";
+
+    fn line(&self) -> String {
+        self.name.clone()
+    }
+
+    fn preview(&self) -> String {
+        format!(
+            " Branch: {}\n Last commit: {}\n Protected: {}\n",
+            self.name, self.commit.sha, self.protected
+        )
+    }
+}
+
+impl Pickable for Milestone {
+    const LIST_NAME: &'static str = "milestones";
+
+    fn line(&self) -> String {
+        format!("({}) {} [{}]", self.number, self.title, self.state)
+    }
+
+    fn preview(&self) -> String {
+        let due_on = match self.due_on {
+            Some(d) => d.format("%Y-%m-%d").to_string(),
+            None => "-".to_string(),
+        };
+        format!(
+            " Milestone {}: {}\n Due on: {}\n Progress: {}\n\n{}\n",
+            self.number,
+            self.title,
+            due_on,
+            progress_bar(self.closed_issues, self.open_issues),
+            self.description
+        )
+    }
+}
+
+impl Pickable for Thread {
+    const LIST_NAME: &'static str = "notifications";
+
+    fn line(&self) -> String {
+        format!(
+            "({}) {} {}: {}",
+            self.id, self.repository.full_name, self.subject.type_, self.subject.title
+        )
+    }
+
+    fn preview(&self) -> String {
+        format!(
+            " Notification {}\n Repo: {}\n {}: {}\n Reason: {}\n Updated at: {}\n",
+            self.id,
+            self.repository.full_name,
+            self.subject.type_,
+            self.subject.title,
+            self.reason,
+            self.updated_at
+        )
+    }
+
+    // Comments of issue/pull request from notification, other subjects (releases, commits) have none
+    fn comments_source(&self) -> Option<(i64, CommentTarget)> {
+        let (_, number) = notifications::subject_issue(self).ok()?;
+        Some((number, CommentTarget::Issue))
+    }
 
-    for issue in list_issues {
-        if issue.number == issue_num {
-            return Ok(Some(issue));
-        }
+    fn comments_repo(&self) -> Option<RepoInfo> {
+        self.repository.full_name.parse::<RepoInfo>().ok()
     }
-    Ok(None)
 }
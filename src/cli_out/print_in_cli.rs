@@ -1,5 +1,6 @@
 use std::error::Error;
 use std::fmt;
+use std::io::{self, Write};
 
 /// Errors returned by the tried print result
 #[derive(Debug, PartialEq, Eq)]
@@ -40,10 +41,22 @@ impl fmt::Display for PrintError {
 }
 
 use octorust::types::{
-    Issue, IssueComment, IssueSimple, MinimalRepository, PullRequestReviewComment, Release,
+    CodeSearchResultItem, CommitComparison, FullRepository, Issue, IssueComment, IssueSimple,
+    Label, Milestone, MinimalRepository, PullRequestReviewComment, Reaction, ReactionRollup,
+    Release, Thread, UserSearchResultItem,
 };
 
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
 use crate::cli_in::set_vars::IssuesListStates;
+use crate::git_utils::branches::BranchInfo;
+use crate::git_utils::labels::LabelChange;
+use crate::git_utils::provision::RepoChange;
+use crate::git_utils::rulesets::{self, RulesetChange};
+use crate::git_utils::search::SearchResult;
+use crate::git_utils::spec_diff::FieldChange;
+use crate::git_utils::status::Dashboard;
 
 pub fn print_release(result: Release) {
     println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
@@ -57,7 +70,46 @@ pub fn print_release(result: Release) {
     println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
 }
 
-pub fn print_issues(list_issues: Vec<IssueSimple>, state: IssuesListStates, numb_of_page: i64) {
+pub fn print_releases(list_releases: Vec<Release>, numb_of_page: i64) {
+    println!(
+        " {} Releases from {} page:",
+        list_releases.len(),
+        numb_of_page
+    );
+    println!();
+    for release in list_releases {
+        println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+        println!(
+            " Release {}: {}{}",
+            release.tag_name,
+            release.name,
+            release_marks(&release)
+        );
+        println!(" Release id: {}", release.id);
+        if let Some(time) = release.published_at {
+            println!(" Published at: {time}");
+        };
+        println!(" Release url: {}", release.html_url);
+        println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+    }
+}
+
+pub fn release_marks(release: &Release) -> String {
+    let mut marks = String::new();
+    if release.draft {
+        marks.push_str(" [draft]");
+    }
+    if release.prerelease {
+        marks.push_str(" [prerelease]");
+    }
+    marks
+}
+
+pub fn print_issues(
+    list_issues: Vec<(IssueSimple, Option<ReactionRollup>)>,
+    state: IssuesListStates,
+    numb_of_page: i64,
+) {
     println!(
         " {} {} Issues from {} page:",
         list_issues.len(),
@@ -65,25 +117,61 @@ pub fn print_issues(list_issues: Vec<IssueSimple>, state: IssuesListStates, numb
         numb_of_page
     );
     println!();
-    for issue in list_issues {
+    for (issue, reactions) in list_issues {
         if issue.pull_request.is_some() {
             continue;
         }
-        println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
-        println!(" Issue {}: {};", issue.number, issue.title);
-        println!(" Body: {}", issue.body);
-        println!(" labels:");
-        for label in issue.labels {
-            println!("   {}: {}", label.name, label.description);
-        }
+        print_issue_card(issue, &reactions, false);
+    }
+}
 
-        if let Some(time) = issue.created_at {
-            println!(" Created at: {time}");
-        };
-        println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+pub fn print_found_issues(result: SearchResult<IssueSimple>) {
+    print_found_header(&result, "issues");
+    for issue in result.items {
+        print_issue_card(issue, &None, true);
     }
 }
 
+fn print_found_header<T>(result: &SearchResult<T>, what: &str) {
+    println!(
+        " Found {} {}, showing {}:",
+        result.total_count,
+        what,
+        result.items.len()
+    );
+    if result.incomplete_results {
+        println!(" Search timed out, results may be incomplete");
+    }
+    println!();
+}
+
+fn print_issue_card(issue: IssueSimple, reactions: &Option<ReactionRollup>, with_repo: bool) {
+    let kind = match issue.pull_request {
+        Some(_) => "Pull request",
+        None => "Issue",
+    };
+
+    println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+    println!(" {} {}: {};", kind, issue.number, issue.title);
+    if with_repo {
+        println!(" Repo: {}", repo_of_issue(&issue));
+        println!(" State: {}", issue.state);
+    }
+    println!(" Body: {}", issue.body);
+    println!(" labels:");
+    for label in issue.labels {
+        println!("   {}: {}", label.name, label.description);
+    }
+    if let Some(reactions) = reactions_line(reactions) {
+        println!(" Reactions: {reactions}");
+    }
+
+    if let Some(time) = issue.created_at {
+        println!(" Created at: {time}");
+    };
+    println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+}
+
 pub fn print_simple_issue(issue: IssueSimple) -> Result<(), Box<dyn Error>> {
     if issue.pull_request.is_some() {
         return Err(Box::new(PrintError {
@@ -115,8 +203,19 @@ pub fn print_issue(issue: Issue) -> Result<(), Box<dyn Error>> {
         }));
     }
 
+    print_issue_or_pull(issue);
+    Ok(())
+}
+
+// Same as `print_issue`, but pull requests are printed too (for places where both can come)
+pub fn print_issue_or_pull(issue: Issue) {
+    let kind = match issue.pull_request {
+        Some(_) => "Pull request",
+        None => "Issue",
+    };
+
     println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
-    println!(" Issue {}: {};", issue.number, issue.title);
+    println!(" {} {}: {};", kind, issue.number, issue.title);
     println!(" State: {}", issue.state);
     println!(" Body: {}", issue.body);
     println!(" labels:");
@@ -125,12 +224,14 @@ pub fn print_issue(issue: Issue) -> Result<(), Box<dyn Error>> {
             println!("   {}: {}", data.name, data.description);
         }
     }
+    if let Some(reactions) = reactions_line(&issue.reactions) {
+        println!(" Reactions: {reactions}");
+    }
 
     if let Some(time) = issue.created_at {
         println!(" Created at: {time}");
     };
     println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
-    Ok(())
 }
 
 pub fn print_url(result: String, description: &str) {
@@ -143,11 +244,155 @@ pub fn print_repos(repos: Vec<MinimalRepository>, owner: String, owner_type: &st
     println!(" Found {} repos in {} {}", repos.len(), owner, owner_type);
 
     for repo in repos {
+        print_repo_card(repo);
+    }
+}
+
+pub fn print_found_repos(result: SearchResult<MinimalRepository>) {
+    print_found_header(&result, "repos");
+    for repo in result.items {
+        print_repo_card(repo);
+    }
+}
+
+pub fn print_repo(repo: FullRepository, latest_release: Option<Release>) {
+    println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+    println!(
+        " Repo {}: {}{}",
+        repo.id,
+        repo.full_name,
+        if repo.archived { " [archived]" } else { "" }
+    );
+    println!(" Description: {}", repo.description);
+    if !repo.topics.is_empty() {
+        println!(" Topics: {}", repo.topics.join(", "));
+    }
+    println!(" Default branch: {}", repo.default_branch);
+    println!(" Visibility: {}", repo.visibility);
+    println!(" Stars: {}", repo.stargazers_count);
+    println!(" Open issues: {}", repo.open_issues_count);
+    match repo.license {
+        Some(license) => println!(" License: {}", license.name),
+        None => println!(" License: none"),
+    }
+    match latest_release {
+        Some(release) => println!(" Latest release: {} ({})", release.tag_name, release.name),
+        None => println!(" Latest release: none"),
+    }
+    println!(" Url: {}", repo.html_url);
+    println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+}
+
+pub fn print_branches(default_branch: &str, list_branches: &[BranchInfo]) {
+    println!(
+        " Found {} branches, compared with {}",
+        list_branches.len(),
+        default_branch
+    );
+
+    for branch in list_branches {
+        let mut marks = String::new();
+        if branch.name == default_branch {
+            marks.push_str(" [default]");
+        }
+        if branch.protected {
+            marks.push_str(" [protected]");
+        }
+        if let Some(number) = branch.merged_pull {
+            marks.push_str(&format!(" [merged #{number}]"));
+        }
+
         println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
-        println!(" Repo {}: {}", repo.id, repo.full_name);
-        println!(" Language: {}", repo.language);
-        println!(" Url: {}", repo.url);
-        println!(" Description: {}", repo.description);
+        println!(" Branch {}{}", branch.name, marks);
+        if branch.name != default_branch {
+            println!(" Ahead: {}, behind: {}", branch.ahead, branch.behind);
+        }
+        println!(
+            " Last commit: {} {} ({})",
+            &branch.sha[..7.min(branch.sha.len())],
+            branch.message,
+            branch.date
+        );
+        println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+    }
+}
+
+pub fn print_comparison(basehead: &str, comparison: CommitComparison) {
+    println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+    println!(" Compare {}: {}", basehead, comparison.status);
+    println!(
+        " Ahead: {}, behind: {}",
+        comparison.ahead_by, comparison.behind_by
+    );
+    println!(" Url: {}", comparison.html_url);
+
+    println!(" Commits ({}):", comparison.total_commits);
+    for commit in &comparison.commits {
+        let author = match &commit.author {
+            Some(a) => a.login.clone(),
+            None => commit
+                .commit
+                .author
+                .as_ref()
+                .map(|a| a.name.clone())
+                .unwrap_or_default(),
+        };
+        println!(
+            "  {} {} @{}",
+            &commit.sha[..7.min(commit.sha.len())],
+            commit.commit.message.lines().next().unwrap_or_default(),
+            author
+        );
+    }
+    if comparison.total_commits > comparison.commits.len() as i64 {
+        println!(
+            "  ... and {} more",
+            comparison.total_commits - comparison.commits.len() as i64
+        );
+    }
+
+    let additions: i64 = comparison.files.iter().map(|f| f.additions).sum();
+    let deletions: i64 = comparison.files.iter().map(|f| f.deletions).sum();
+    println!(
+        " Files ({}): +{} -{}",
+        comparison.files.len(),
+        additions,
+        deletions
+    );
+    for file in &comparison.files {
+        println!(
+            "  {} {} +{} -{}",
+            file.status, file.filename, file.additions, file.deletions
+        );
+    }
+    println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+}
+
+fn print_repo_card(repo: MinimalRepository) {
+    println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+    println!(" Repo {}: {}", repo.id, repo.full_name);
+    println!(" Language: {}", repo.language);
+    println!(" Url: {}", repo.url);
+    println!(" Description: {}", repo.description);
+    println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+}
+
+pub fn print_found_code(result: SearchResult<CodeSearchResultItem>) {
+    print_found_header(&result, "files");
+    for file in result.items {
+        println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+        println!(" {}: {}", file.repository.full_name, file.path);
+        println!(" Url: {}", file.html_url);
+        println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+    }
+}
+
+pub fn print_found_users(result: SearchResult<UserSearchResultItem>) {
+    print_found_header(&result, "users");
+    for user in result.items {
+        println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+        println!(" {} {}: {}", user.type_, user.id, user.login);
+        println!(" Url: {}", user.html_url);
         println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
     }
 }
@@ -171,6 +416,9 @@ pub fn print_comments(list_comments: Vec<IssueComment>) -> Result<(), Box<dyn Er
             }
         );
         println!(" Body: {}", comment.body);
+        if let Some(reactions) = reactions_line(&comment.reactions) {
+            println!(" Reactions: {reactions}");
+        }
         println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
     }
     Ok(())
@@ -199,7 +447,571 @@ pub fn print_review_comments(
         println!(" Body: {}", comment.body);
         println!(" For line: {}", comment.line);
         println!(" In file: {}", comment.path);
+        if let Some(reactions) = reactions_line(&comment.reactions) {
+            println!(" Reactions: {reactions}");
+        }
         println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
     }
     Ok(())
 }
+
+pub fn print_labels(labels: Vec<Label>, full_name: String) {
+    println!(" Found {} labels in {}", labels.len(), full_name);
+
+    for label in labels {
+        println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+        println!(" Label: {}", label.name);
+        println!(" Color: #{}", label.color);
+        println!(" Description: {}", label.description);
+        println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+    }
+}
+
+pub fn print_label_changes(changes: &[LabelChange]) {
+    if changes.is_empty() {
+        println!(" Labels are up to date");
+        return;
+    }
+
+    println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+    for change in changes {
+        match change {
+            LabelChange::Create(new) => {
+                println!(" + {} (#{}): {}", new.name, new.color, new.description);
+            }
+            LabelChange::Update { old, new } => {
+                println!(
+                    " ~ {} (#{}): {} -> {} (#{}): {}",
+                    old.name, old.color, old.description, new.name, new.color, new.description
+                );
+            }
+            LabelChange::Delete(old) => {
+                println!(" - {} (#{}): {}", old.name, old.color, old.description);
+            }
+        }
+    }
+    println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+}
+
+pub fn print_field_changes(changes: &[FieldChange]) {
+    for change in changes {
+        println!(" ~ {}: {} -> {}", change.path, change.old, change.new);
+    }
+}
+
+pub fn print_rulesets(list_rulesets: &[Value]) {
+    println!(" Found {} rulesets", list_rulesets.len());
+
+    for ruleset in list_rulesets {
+        println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+        println!(" Ruleset {}: {}", ruleset["id"], rulesets::name_of(ruleset));
+        println!(
+            " Target: {}, enforcement: {}",
+            ruleset["target"].as_str().unwrap_or_default(),
+            ruleset["enforcement"].as_str().unwrap_or_default()
+        );
+        println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+    }
+}
+
+pub fn print_ruleset_changes(changes: &[RulesetChange]) {
+    if changes.is_empty() {
+        println!(" Rulesets are up to date");
+        return;
+    }
+
+    println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+    for change in changes {
+        match change {
+            RulesetChange::Create(new) => println!(" + {}", rulesets::name_of(new)),
+            RulesetChange::Update {
+                id, name, fields, ..
+            } => {
+                println!(" ~ {name} ({id})");
+                print_field_changes(fields);
+            }
+            RulesetChange::Delete { id, name } => println!(" - {name} ({id})"),
+        }
+    }
+    println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+}
+
+pub fn print_repo_changes(org: &str, changes: &[RepoChange]) {
+    if changes.is_empty() {
+        println!(" Repos of {org} are up to date");
+        return;
+    }
+
+    println!(" {} repos of {org} to change:", changes.len());
+    println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+    for change in changes {
+        match change {
+            RepoChange::Create(spec) => println!(" + {}", spec.name),
+            RepoChange::Update {
+                spec,
+                settings,
+                topics,
+                teams,
+                labels,
+            } => {
+                println!(" ~ {}", spec.name);
+                print_field_changes(settings);
+                if let Some((old, new)) = topics {
+                    println!(" ~ topics: [{}] -> [{}]", old.join(", "), new.join(", "));
+                }
+                for team in teams {
+                    println!(
+                        " ~ team {}: {} -> {}",
+                        team.slug,
+                        team.old.as_deref().unwrap_or("none"),
+                        team.new.as_deref().unwrap_or("none")
+                    );
+                }
+                for label in labels {
+                    match label {
+                        LabelChange::Create(new) => println!(" + label {}", new.name),
+                        LabelChange::Update { old, new } => {
+                            println!(" ~ label {} -> {}", old.name, new.name)
+                        }
+                        LabelChange::Delete(old) => println!(" - label {}", old.name),
+                    }
+                }
+            }
+            RepoChange::Archive(name) => println!(" - {name} (archive)"),
+        }
+    }
+    println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+}
+
+/// Bar like `[######----] 60% (6 closed / 4 open)`
+pub fn progress_bar(closed: i64, open: i64) -> String {
+    const WIDTH: i64 = 20;
+    let total = (closed + open).max(1);
+    let done = closed * WIDTH / total;
+    let percent = closed * 100 / total;
+
+    format!(
+        "[{}{}] {}% ({} closed / {} open)",
+        "#".repeat(done as usize),
+        "-".repeat((WIDTH - done) as usize),
+        percent,
+        closed,
+        open
+    )
+}
+
+pub fn print_milestones(milestones: Vec<Milestone>, state: IssuesListStates) {
+    println!(" {} {} milestones:", milestones.len(), state);
+
+    for milestone in milestones {
+        println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+        println!(" Milestone {}: {}", milestone.number, milestone.title);
+        println!(" State: {}", milestone.state);
+        if let Some(due_on) = milestone.due_on {
+            println!(" Due on: {}", due_on.format("%Y-%m-%d"));
+        }
+        println!(
+            " Progress: {}",
+            progress_bar(milestone.closed_issues, milestone.open_issues)
+        );
+        println!(" Description: {}", milestone.description);
+        println!(" Url: {}", milestone.html_url);
+        println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+    }
+}
+
+/// Emoji of reaction content
+fn reaction_emoji(content: &str) -> &'static str {
+    match content {
+        "+1" => "👍",
+        "-1" => "👎",
+        "laugh" => "😄",
+        "hooray" => "🎉",
+        "confused" => "😕",
+        "heart" => "❤️",
+        "rocket" => "🚀",
+        "eyes" => "👀",
+        _ => "?",
+    }
+}
+
+/// Not zero reaction counts like `👍 3  🎉 1`
+fn reactions_line(reactions: &Option<ReactionRollup>) -> Option<String> {
+    let r = reactions.as_ref()?;
+    let counts = [
+        ("+1", r.plus_one),
+        ("-1", r.minus_one),
+        ("laugh", r.laugh),
+        ("hooray", r.hooray),
+        ("confused", r.confused),
+        ("heart", r.heart),
+        ("rocket", r.rocket),
+        ("eyes", r.eyes),
+    ];
+
+    let line: Vec<String> = counts
+        .iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(content, count)| format!("{} {}", reaction_emoji(content), count))
+        .collect();
+
+    if line.is_empty() {
+        None
+    } else {
+        Some(line.join("  "))
+    }
+}
+
+pub fn print_reactions(reactions: Vec<Reaction>) {
+    let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+    for reaction in reactions {
+        let content = reaction.content.to_string();
+        let who = match reaction.user {
+            Some(u) => u.login,
+            None => "ghost".to_string(),
+        };
+        match groups.iter_mut().find(|(c, _)| *c == content) {
+            Some((_, users)) => users.push(who),
+            None => groups.push((content, vec![who])),
+        }
+    }
+
+    println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+    if groups.is_empty() {
+        println!(" No reactions");
+    }
+    for (content, users) in groups {
+        println!(
+            " {} {} ({}): {}",
+            reaction_emoji(&content),
+            content,
+            users.len(),
+            users.join(", ")
+        );
+    }
+    println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+}
+
+/// Time like `5 minutes ago` or `3 days ago`
+pub fn relative_time(time: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let seconds = (now - time).num_seconds().max(0);
+
+    let (value, unit) = match seconds {
+        0..=59 => return "just now".to_string(),
+        60..=3599 => (seconds / 60, "minute"),
+        3600..=86399 => (seconds / 3600, "hour"),
+        86400..=2591999 => (seconds / 86400, "day"),
+        2592000..=31535999 => (seconds / 2592000, "month"),
+        _ => (seconds / 31536000, "year"),
+    };
+
+    if value == 1 {
+        format!("1 {unit} ago")
+    } else {
+        format!("{value} {unit}s ago")
+    }
+}
+
+/// Who made timeline event, when and what was done
+fn describe_event(event: &Value) -> (String, Option<DateTime<Utc>>, String) {
+    let login = |v: &Value| v["login"].as_str().unwrap_or("ghost").to_string();
+    let time = |v: &Value| {
+        v.as_str()
+            .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+            .map(|t| t.with_timezone(&Utc))
+    };
+
+    let kind = event["event"].as_str().unwrap_or("");
+    let who = login(&event["actor"]);
+    let when = time(&event["created_at"]);
+
+    match kind {
+        "commented" => (
+            login(&event["user"]),
+            when,
+            format!("commented:\n {}", event["body"].as_str().unwrap_or("")),
+        ),
+        "labeled" | "unlabeled" => (
+            who,
+            when,
+            format!(
+                "{} label `{}`",
+                if kind == "labeled" {
+                    "added"
+                } else {
+                    "removed"
+                },
+                event["label"]["name"].as_str().unwrap_or("")
+            ),
+        ),
+        "assigned" | "unassigned" => (
+            who,
+            when,
+            format!(
+                "{} {}",
+                kind,
+                event["assignee"]["login"].as_str().unwrap_or("")
+            ),
+        ),
+        "milestoned" | "demilestoned" => (
+            who,
+            when,
+            format!(
+                "{} `{}`",
+                if kind == "milestoned" {
+                    "added to milestone"
+                } else {
+                    "removed from milestone"
+                },
+                event["milestone"]["title"].as_str().unwrap_or("")
+            ),
+        ),
+        "renamed" => (
+            who,
+            when,
+            format!(
+                "renamed from `{}` to `{}`",
+                event["rename"]["from"].as_str().unwrap_or(""),
+                event["rename"]["to"].as_str().unwrap_or("")
+            ),
+        ),
+        "cross-referenced" => {
+            let source = &event["source"]["issue"];
+            let kind = if source["pull_request"].is_object() {
+                "pull request"
+            } else {
+                "issue"
+            };
+            (
+                who,
+                when,
+                format!(
+                    "mentioned in {} {}#{}: {}",
+                    kind,
+                    source["repository"]["full_name"].as_str().unwrap_or(""),
+                    source["number"],
+                    source["title"].as_str().unwrap_or("")
+                ),
+            )
+        }
+        "committed" => (
+            event["author"]["name"].as_str().unwrap_or("").to_string(),
+            time(&event["author"]["date"]),
+            format!(
+                "committed {}: {}",
+                event["sha"].as_str().unwrap_or("").get(..7).unwrap_or(""),
+                event["message"]
+                    .as_str()
+                    .unwrap_or("")
+                    .lines()
+                    .next()
+                    .unwrap_or("")
+            ),
+        ),
+        "referenced" => (
+            who,
+            when,
+            format!(
+                "referenced in commit {}",
+                event["commit_id"]
+                    .as_str()
+                    .unwrap_or("")
+                    .get(..7)
+                    .unwrap_or("")
+            ),
+        ),
+        "connected" | "disconnected" => (
+            who,
+            when,
+            format!("{kind} pull request that will close this issue"),
+        ),
+        "closed" => {
+            let mut text = match event["state_reason"].as_str() {
+                Some(reason) => format!("closed as {reason}"),
+                None => "closed".to_string(),
+            };
+            if let Some(commit) = event["commit_id"].as_str() {
+                text.push_str(&format!(" in commit {}", commit.get(..7).unwrap_or(commit)));
+            }
+            (who, when, text)
+        }
+        "reviewed" => (
+            login(&event["user"]),
+            time(&event["submitted_at"]),
+            format!(
+                "reviewed: {}",
+                event["state"].as_str().unwrap_or("").to_lowercase()
+            ),
+        ),
+        _ => (who, when, kind.replace('_', " ")),
+    }
+}
+
+pub fn print_timeline(events: Vec<Value>, number: i64) {
+    let now = Utc::now();
+
+    let mut events: Vec<(String, Option<DateTime<Utc>>, String)> =
+        events.iter().map(describe_event).collect();
+    events.sort_by_key(|(_, when, _)| *when);
+
+    println!(" Timeline of issue {} ({} events):", number, events.len());
+    for (who, when, text) in events {
+        let when = match when {
+            Some(t) => relative_time(t, now),
+            None => String::new(),
+        };
+        println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+        println!(" {who} {when}");
+        println!(" {text}");
+        println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+    }
+}
+
+fn repo_of_issue(issue: &IssueSimple) -> String {
+    let parts = issue.repository_url.rsplitn(3, '/').collect::<Vec<_>>();
+    match parts.as_slice() {
+        [name, owner, ..] => format!("{owner}/{name}"),
+        _ => String::new(),
+    }
+}
+
+fn checks_mark(state: &str) -> &'static str {
+    match state {
+        "SUCCESS" => "✓ passed",
+        "FAILURE" | "ERROR" => "✗ failed",
+        "PENDING" | "EXPECTED" => "• pending",
+        _ => "- no checks",
+    }
+}
+
+pub fn print_status(dashboard: Dashboard) {
+    let now = Utc::now();
+
+    println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+    println!(" Issues assigned to you ({}):", dashboard.assigned.len());
+    for issue in &dashboard.assigned {
+        println!(
+            "   {}#{}: {}",
+            repo_of_issue(issue),
+            issue.number,
+            issue.title
+        );
+    }
+    println!("├────────────────────────────────────────────────────────────────────────────────────────────────");
+    println!(
+        " Pull requests waiting for your review ({}):",
+        dashboard.review_requested.len()
+    );
+    for pull in &dashboard.review_requested {
+        println!("   {}#{}: {}", repo_of_issue(pull), pull.number, pull.title);
+    }
+    println!("├────────────────────────────────────────────────────────────────────────────────────────────────");
+    println!(" Your open pull requests ({}):", dashboard.my_pulls.len());
+    for pull in &dashboard.my_pulls {
+        let draft = if pull.draft { " [draft]" } else { "" };
+        println!(
+            "   {} {}#{}: {}{}",
+            checks_mark(&pull.checks),
+            pull.repo,
+            pull.number,
+            pull.title,
+            draft
+        );
+    }
+    println!("├────────────────────────────────────────────────────────────────────────────────────────────────");
+    println!(" Recent mentions ({}):", dashboard.mentions.len());
+    for thread in &dashboard.mentions {
+        let when = match DateTime::parse_from_rfc3339(&thread.updated_at) {
+            Ok(t) => relative_time(t.with_timezone(&Utc), now),
+            Err(_) => String::new(),
+        };
+        let unread = if thread.unread { "*" } else { " " };
+        println!(
+            "  {}{}: {} ({})",
+            unread, thread.repository.full_name, thread.subject.title, when
+        );
+    }
+    println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+}
+
+pub fn print_notifications(threads: &[Thread]) {
+    let now = Utc::now();
+
+    for thread in threads {
+        let when = match DateTime::parse_from_rfc3339(&thread.updated_at) {
+            Ok(t) => relative_time(t.with_timezone(&Utc), now),
+            Err(_) => String::new(),
+        };
+        let unread = if thread.unread { " (unread)" } else { "" };
+
+        println!("╭────────────────────────────────────────────────────────────────────────────────────────────────");
+        println!(
+            " Notification {} in {}{}",
+            thread.id, thread.repository.full_name, unread
+        );
+        println!(" {}: {}", thread.subject.type_, thread.subject.title);
+        println!(" Reason: {}, updated {}", thread.reason, when);
+        println!("╰────────────────────────────────────────────────────────────────────────────────────────────────");
+    }
+}
+
+pub fn human_size(bytes: u64) -> String {
+    let units = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{:.1} {}", size, units[unit])
+    }
+}
+
+// Rewrite current line with progress of transfer, `action` is like "Downloading"
+pub fn print_progress(action: &str, name: &str, done: u64, total: u64) {
+    if total > 0 {
+        print!(
+            "\r {} {}: {} / {} ({}%)",
+            action,
+            name,
+            human_size(done),
+            human_size(total),
+            done * 100 / total
+        );
+    } else {
+        print!("\r {} {}: {}", action, name, human_size(done));
+    }
+    let _ = io::stdout().flush();
+}
+
+#[cfg(test)]
+mod print_tests {
+    use super::*;
+    use chrono::Duration;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Duration::seconds(30), "just now")]
+    #[case(Duration::minutes(1), "1 minute ago")]
+    #[case(Duration::hours(5), "5 hours ago")]
+    #[case(Duration::days(3), "3 days ago")]
+    #[case(Duration::days(65), "2 months ago")]
+    #[case(Duration::days(800), "2 years ago")]
+    fn relative(#[case] ago: Duration, #[case] expected: &str) {
+        let now = Utc::now();
+        assert_eq!(relative_time(now - ago, now), expected);
+    }
+
+    #[rstest]
+    #[case(0, "0 B")]
+    #[case(1023, "1023 B")]
+    #[case(1536, "1.5 KB")]
+    #[case(5 * 1024 * 1024, "5.0 MB")]
+    fn human_size_test(#[case] bytes: u64, #[case] expected: &str) {
+        assert_eq!(human_size(bytes), expected);
+    }
+}
@@ -1,19 +1,44 @@
-use crate::cli_in::set_vars::{IssuesListStates, States};
+use octorust::types::Order;
+
+use crate::cli_in::set_vars::{DueDate, IssuesListSorts, IssuesListStates, MilestoneRef, States};
 
 pub struct ListIssueArgs {
     pub creator: String,
     pub assignee: String,
     pub state: IssuesListStates,
     pub labels: String,
+    pub milestone: Option<MilestoneRef>,
+    pub sort: IssuesListSorts,
     pub numb_of_page: i64,
     pub iss_on_page: i64,
 }
 
+pub struct SearchArgs {
+    pub query: String,
+    pub sort: String,
+    pub order: Order,
+    pub limit: i64,
+}
+
+pub struct CreateIssueArgs {
+    pub title: String,
+    pub body: String,
+    pub assignees: String,
+    pub labels: String,
+    pub milestone: Option<MilestoneRef>,
+}
+
 pub struct UpdateIssueArgs {
     pub title: Option<String>,
     pub body: Option<String>,
-    pub number: i64,
-    pub state: States,
+    pub state: Option<States>,
+    pub milestone: Option<MilestoneRef>,
+    pub labels: Option<Vec<String>>,
+    pub assignees: Option<Vec<String>>,
+    pub add_labels: Vec<String>,
+    pub remove_labels: Vec<String>,
+    pub add_assignees: Vec<String>,
+    pub remove_assignees: Vec<String>,
 }
 
 pub struct CreateRepoArgs {
@@ -35,6 +60,23 @@ pub struct CreateRepoArgs {
     pub name: String,
 }
 
+pub struct EditRepoArgs {
+    pub allow_auto_merge: Option<bool>,
+    pub allow_merge_commit: Option<bool>,
+    pub allow_rebase_merge: Option<bool>,
+    pub allow_squash_merge: Option<bool>,
+    pub delete_branch_on_merge: Option<bool>,
+    pub has_issues: Option<bool>,
+    pub has_projects: Option<bool>,
+    pub has_wiki: Option<bool>,
+    pub is_template: Option<bool>,
+    pub private: Option<bool>,
+    pub description: Option<String>,
+    pub homepage: Option<String>,
+    pub default_branch: Option<String>,
+    pub topics: Option<Vec<String>>,
+}
+
 pub struct CreateRepoFromTemplateArgs {
     pub description: String,
     pub include_all_branches: Option<bool>,
@@ -50,3 +92,18 @@ pub struct CreateReleaseArgs {
     pub prerelease: Option<bool>,
     pub target_commitish: String,
 }
+
+pub struct EditReleaseArgs {
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub draft: Option<bool>,
+    pub prerelease: Option<bool>,
+    pub make_latest: Option<String>,
+}
+
+pub struct EditMilestoneArgs {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub due_on: Option<DueDate>,
+    pub state: Option<States>,
+}
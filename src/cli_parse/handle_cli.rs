@@ -4,10 +4,18 @@ use octorust::{self, Client};
 
 use crate::cli_in::read_cli::Args;
 use crate::cli_in::read_cli::CliCommand;
+use crate::cli_parse::handle_commands::handle_branch::handle_branch_command;
 use crate::cli_parse::handle_commands::handle_comment::handle_comment_command;
 use crate::cli_parse::handle_commands::handle_issue::handle_issue_command;
+use crate::cli_parse::handle_commands::handle_label::handle_label_command;
+use crate::cli_parse::handle_commands::handle_milestone::handle_milestone_command;
+use crate::cli_parse::handle_commands::handle_notification::handle_notification_command;
+use crate::cli_parse::handle_commands::handle_reaction::handle_reaction_command;
 use crate::cli_parse::handle_commands::handle_release::handle_release_command;
 use crate::cli_parse::handle_commands::handle_repo::handle_repo_command;
+use crate::cli_parse::handle_commands::handle_search::handle_search_command;
+use crate::cli_parse::handle_commands::handle_status::handle_status_command;
+use crate::cli_parse::handle_commands::handle_tui::handle_tui_command;
 
 pub async fn handle_cli_command(args: Args, github_client: Client) -> Result<(), Box<dyn Error>> {
     match args.command {
@@ -21,6 +29,26 @@ pub async fn handle_cli_command(args: Args, github_client: Client) -> Result<(),
             Ok(())
         }
 
+        CliCommand::Label { subcommand } => {
+            handle_label_command(github_client, subcommand).await?;
+            Ok(())
+        }
+
+        CliCommand::Milestone { subcommand } => {
+            handle_milestone_command(github_client, subcommand).await?;
+            Ok(())
+        }
+
+        CliCommand::Notification { subcommand } => {
+            handle_notification_command(github_client, subcommand).await?;
+            Ok(())
+        }
+
+        CliCommand::Reaction { subcommand } => {
+            handle_reaction_command(github_client, subcommand).await?;
+            Ok(())
+        }
+
         CliCommand::Repo { subcommand } => {
             handle_repo_command(github_client, subcommand).await?;
             Ok(())
@@ -30,5 +58,25 @@ pub async fn handle_cli_command(args: Args, github_client: Client) -> Result<(),
             handle_release_command(github_client, subcommand).await?;
             Ok(())
         }
+
+        CliCommand::Branch { subcommand } => {
+            handle_branch_command(github_client, subcommand).await?;
+            Ok(())
+        }
+
+        CliCommand::Search { subcommand } => {
+            handle_search_command(subcommand).await?;
+            Ok(())
+        }
+
+        CliCommand::Status { limit } => {
+            handle_status_command(github_client, limit).await?;
+            Ok(())
+        }
+
+        CliCommand::Tui { subcommand } => {
+            handle_tui_command(github_client, subcommand).await?;
+            Ok(())
+        }
     }
 }
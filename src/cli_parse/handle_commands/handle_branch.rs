@@ -0,0 +1,233 @@
+use std::error::Error;
+use std::io::{self, Write};
+
+use octorust::{self, Client};
+
+use crate::cli_in::branch_command::BranchCommand;
+use crate::cli_out::fuzzy_select::choose_one;
+use crate::cli_out::print_in_cli::{print_branches, print_comparison};
+use crate::git_utils::branches;
+use crate::git_utils::repo_info::{Repo, RepoInfo, RepoName, RepoOwner};
+use crate::git_utils::repos;
+
+pub async fn handle_branch_command(
+    github_client: Client,
+    subcommand: BranchCommand,
+) -> Result<(), Box<dyn Error>> {
+    match subcommand {
+        BranchCommand::List { owner, repo } => {
+            handle_list(github_client, owner, repo).await?;
+            Ok(())
+        }
+
+        BranchCommand::Create {
+            owner,
+            repo,
+            name,
+            from,
+        } => {
+            handle_create(github_client, owner, repo, name, from).await?;
+            Ok(())
+        }
+
+        BranchCommand::Delete {
+            owner,
+            repo,
+            name,
+            merged,
+            yes,
+        } => {
+            if merged {
+                handle_delete_merged(github_client, owner, repo, yes).await?;
+            } else {
+                handle_delete(github_client, owner, repo, name).await?;
+            }
+            Ok(())
+        }
+
+        BranchCommand::Rename {
+            owner,
+            repo,
+            name,
+            new_name,
+        } => {
+            handle_rename(github_client, owner, repo, name, new_name).await?;
+            Ok(())
+        }
+
+        BranchCommand::Compare {
+            owner,
+            repo,
+            basehead,
+        } => {
+            handle_compare(github_client, owner, repo, basehead).await?;
+            Ok(())
+        }
+    }
+}
+
+async fn handle_list(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let (default_branch, list_branches) = branches::get_all(&github_client, &repo_info).await?;
+
+    print_branches(&default_branch, &list_branches);
+    Ok(())
+}
+
+async fn handle_create(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    name: String,
+    from: Option<String>,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let from = match from {
+        Some(f) => f,
+        None => repos::get_default_branch(&github_client, &repo_info).await?,
+    };
+    let sha = branches::resolve_sha(&github_client, &repo_info, &from).await?;
+
+    let result = branches::create(&github_client, &repo_info, &name, &sha).await?;
+
+    println!("{name} from {from} ({sha}): {result}");
+    Ok(())
+}
+
+async fn handle_delete(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    name: Option<String>,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let name = match name {
+        Some(n) => n,
+        None => match choose_branch(&github_client, &repo_info).await? {
+            Some(n) => n,
+            None => {
+                println!("Branch not choosed or not find");
+                return Ok(());
+            }
+        },
+    };
+
+    let result = branches::delete(&github_client, &repo_info, &name).await?;
+
+    println!("{name}: {result}");
+    Ok(())
+}
+
+async fn handle_delete_merged(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    yes: bool,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let (default_branch, list_branches) = branches::get_all(&github_client, &repo_info).await?;
+    let merged = branches::merged(&default_branch, &list_branches);
+
+    if merged.is_empty() {
+        println!("No merged branches");
+        return Ok(());
+    }
+
+    println!("Branches with merged pull requests:");
+    for (name, number) in &merged {
+        println!(" {name} (#{number})");
+    }
+    if !yes {
+        print!("Delete {} branches? [y/N]: ", merged.len());
+        io::stdout().flush()?;
+
+        let mut answer = String::new();
+        io::stdin().read_line(&mut answer)?;
+        if !answer.trim().eq_ignore_ascii_case("y") {
+            println!("Nothing is deleted");
+            return Ok(());
+        }
+    }
+
+    for (name, _) in merged {
+        let result = branches::delete(&github_client, &repo_info, name).await?;
+        println!("{name}: {result}");
+    }
+    Ok(())
+}
+
+async fn handle_rename(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    name: Option<String>,
+    new_name: String,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let name = match name {
+        Some(n) => n,
+        None => match choose_branch(&github_client, &repo_info).await? {
+            Some(n) => n,
+            None => {
+                println!("Branch not choosed or not find");
+                return Ok(());
+            }
+        },
+    };
+
+    let result = branches::rename(&github_client, &repo_info, &name, &new_name).await?;
+
+    println!("{name} renamed to {result}");
+    Ok(())
+}
+
+async fn handle_compare(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    basehead: String,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let comparison = branches::compare(&github_client, &repo_info, &basehead).await?;
+
+    print_comparison(&basehead, comparison);
+    Ok(())
+}
+
+/// Pick branch name from all branches in repo
+async fn choose_branch(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+) -> Result<Option<String>, Box<dyn Error>> {
+    let list_branches = branches::get_short_list(github_client, repo_info).await?;
+
+    Ok(choose_one(list_branches, Some(repo_info))?.map(|b| b.name))
+}
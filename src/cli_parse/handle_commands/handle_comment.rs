@@ -1,11 +1,15 @@
+use octorust::types::IssuesListState;
 use octorust::{self, Client};
 use std::error::Error;
 
 use crate::cli_in::comment_command::CommentCommand;
-use crate::cli_in::set_vars::{CommentTarget, Orders, Sorts};
+use crate::cli_in::set_vars::{CommentTarget, IssuesListSorts, IssuesListStates, Orders, Sorts};
+use crate::cli_out::fuzzy_select::choose_one;
 use crate::cli_out::print_in_cli::{print_comments, print_review_comments};
+use crate::cli_parse::entities::ListIssueArgs;
 use crate::git_utils::comments;
 use crate::git_utils::repo_info::{Repo, RepoInfo, RepoName, RepoOwner};
+use crate::git_utils::{issues, pulls};
 
 pub async fn handle_comment_command(
     github_client: Client,
@@ -85,7 +89,7 @@ async fn handle_get_all(
     github_client: Client,
     owner: Option<RepoOwner>,
     repo: Option<RepoName>,
-    number: i64,
+    number: Option<i64>,
     target: CommentTarget,
 ) -> Result<(), Box<dyn Error>> {
     let repo_info = match owner {
@@ -93,6 +97,17 @@ async fn handle_get_all(
         None => RepoInfo::new(Repo::Current, None, None)?,
     };
 
+    let number = match number {
+        Some(n) => n,
+        None => match choose_number(&github_client, &repo_info, &target).await? {
+            Some(n) => n,
+            None => {
+                println!("Issue or pull request not choosed or not find");
+                return Ok(());
+            }
+        },
+    };
+
     let result = comments::get_all(&github_client, &repo_info, &number).await?;
 
     print_comments(result)?;
@@ -120,7 +135,7 @@ async fn handle_get_all_from_review(
     github_client: Client,
     owner: Option<RepoOwner>,
     repo: Option<RepoName>,
-    number: i64,
+    number: Option<i64>,
     sort: Sorts,
     order: Orders,
 ) -> Result<(), Box<dyn Error>> {
@@ -129,6 +144,19 @@ async fn handle_get_all_from_review(
         None => RepoInfo::new(Repo::Current, None, None)?,
     };
 
+    let number = match number {
+        Some(n) => n,
+        None => {
+            match choose_number(&github_client, &repo_info, &CommentTarget::PullRequest).await? {
+                Some(n) => n,
+                None => {
+                    println!("Pull request not choosed or not find");
+                    return Ok(());
+                }
+            }
+        }
+    };
+
     let result =
         comments::get_all_from_review(&github_client, &repo_info, &number, sort.0, order.0).await?;
 
@@ -140,7 +168,7 @@ async fn handle_update(
     github_client: Client,
     owner: Option<RepoOwner>,
     repo: Option<RepoName>,
-    comment_id: i64,
+    comment_id: Option<i64>,
     body: String,
 ) -> Result<(), Box<dyn Error>> {
     let repo_info = match owner {
@@ -148,6 +176,17 @@ async fn handle_update(
         None => RepoInfo::new(Repo::Current, None, None)?,
     };
 
+    let comment_id = match comment_id {
+        Some(id) => id,
+        None => match choose_comment_id(&github_client, &repo_info).await? {
+            Some(id) => id,
+            None => {
+                println!("Comment not choosed or not find");
+                return Ok(());
+            }
+        },
+    };
+
     let result = comments::update(&github_client, &repo_info, &comment_id, &body).await?;
 
     println!("{result}");
@@ -158,15 +197,73 @@ async fn handle_delete(
     github_client: Client,
     owner: Option<RepoOwner>,
     repo: Option<RepoName>,
-    comment_id: i64,
+    comment_id: Option<i64>,
 ) -> Result<(), Box<dyn Error>> {
     let repo_info = match owner {
         Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
         None => RepoInfo::new(Repo::Current, None, None)?,
     };
 
+    let comment_id = match comment_id {
+        Some(id) => id,
+        None => match choose_comment_id(&github_client, &repo_info).await? {
+            Some(id) => id,
+            None => {
+                println!("Comment not choosed or not find");
+                return Ok(());
+            }
+        },
+    };
+
     let result = comments::delete(&github_client, &repo_info, &comment_id).await?;
 
     println!("{result}");
     Ok(())
 }
+
+/// Pick comment id from all comments in repo
+async fn choose_comment_id(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+) -> Result<Option<i64>, Box<dyn Error>> {
+    let list_comments = comments::get_all_from_repo(github_client, repo_info).await?;
+
+    let choosed_comment = choose_one(list_comments, Some(repo_info))?;
+
+    Ok(choosed_comment.map(|c| c.id))
+}
+
+/// Pick number of open issue or pull request, list is chosen by target
+async fn choose_number(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    target: &CommentTarget,
+) -> Result<Option<i64>, Box<dyn Error>> {
+    match target {
+        CommentTarget::PullRequest => {
+            let list_pulls =
+                pulls::get_list(github_client, repo_info, IssuesListState::Open, 1, 100).await?;
+
+            Ok(choose_one(list_pulls, Some(repo_info))?.map(|p| p.number))
+        }
+        CommentTarget::Issue => {
+            let command_args = ListIssueArgs {
+                creator: String::new(),
+                assignee: String::new(),
+                state: IssuesListStates(IssuesListState::Open),
+                labels: String::new(),
+                milestone: None,
+                sort: IssuesListSorts::Created,
+                numb_of_page: 1,
+                iss_on_page: 100,
+            };
+            let list_issues: Vec<_> = issues::get_list(github_client, repo_info, &command_args)
+                .await?
+                .into_iter()
+                .filter(|iss| iss.pull_request.is_none())
+                .collect();
+
+            Ok(choose_one(list_issues, Some(repo_info))?.map(|iss| iss.number))
+        }
+    }
+}
@@ -1,16 +1,23 @@
+use octorust::types::{IssueSimple, IssuesListState};
 use octorust::{self, Client};
 use std::error::Error;
+use std::io;
 
 use crate::cli_in::issue_command::IssueCommand;
-use crate::cli_out::fuzzy_select::choose_issue;
+use crate::cli_in::set_vars::{CloseReason, IssuesListSorts, IssuesListStates, LockReasons};
+use crate::cli_out::fuzzy_select::{choose_many, choose_one};
 use crate::cli_out::print_in_cli::print_comments;
 use crate::cli_out::print_in_cli::print_issue;
 use crate::cli_out::print_in_cli::print_issues;
 use crate::cli_out::print_in_cli::print_simple_issue;
+use crate::cli_out::print_in_cli::print_timeline;
+use crate::cli_out::print_in_cli::print_url;
+use crate::cli_parse::entities::CreateIssueArgs;
 use crate::cli_parse::entities::ListIssueArgs;
 use crate::cli_parse::entities::UpdateIssueArgs;
 use crate::git_utils::comments;
 use crate::git_utils::issues;
+use crate::git_utils::labels;
 use crate::git_utils::repo_info::Repo;
 use crate::git_utils::repo_info::RepoInfo;
 use crate::git_utils::repo_info::{RepoName, RepoOwner};
@@ -27,6 +34,8 @@ pub async fn handle_issue_command(
             assignee,
             state,
             labels,
+            milestone,
+            sort,
             numb_of_page,
             iss_on_page,
         } => {
@@ -35,6 +44,8 @@ pub async fn handle_issue_command(
                 assignee,
                 state,
                 labels,
+                milestone,
+                sort,
                 numb_of_page,
                 iss_on_page,
             };
@@ -51,6 +62,15 @@ pub async fn handle_issue_command(
             Ok(())
         }
 
+        IssueCommand::Timeline {
+            owner,
+            repo,
+            number,
+        } => {
+            handle_timeline(github_client, owner, repo, number).await?;
+            Ok(())
+        }
+
         IssueCommand::GetFromList {
             owner,
             repo,
@@ -58,6 +78,8 @@ pub async fn handle_issue_command(
             assignee,
             state,
             labels,
+            milestone,
+            sort,
             numb_of_page,
             iss_on_page,
         } => {
@@ -66,6 +88,8 @@ pub async fn handle_issue_command(
                 assignee,
                 state,
                 labels,
+                milestone,
+                sort,
                 numb_of_page,
                 iss_on_page,
             };
@@ -80,8 +104,17 @@ pub async fn handle_issue_command(
             body,
             assignees,
             labels,
+            milestone,
+            check_labels,
         } => {
-            handle_create(github_client, owner, repo, title, body, assignees, labels).await?;
+            let command_args = CreateIssueArgs {
+                title,
+                body,
+                assignees,
+                labels,
+                milestone,
+            };
+            handle_create(github_client, owner, repo, command_args, check_labels).await?;
             Ok(())
         }
 
@@ -90,8 +123,29 @@ pub async fn handle_issue_command(
             repo,
             number,
             comment,
+            reason,
+            duplicate_of,
         } => {
-            handle_close(github_client, owner, repo, number, comment).await?;
+            handle_close(
+                github_client,
+                owner,
+                repo,
+                number,
+                comment,
+                reason,
+                duplicate_of,
+            )
+            .await?;
+            Ok(())
+        }
+
+        IssueCommand::Reopen {
+            owner,
+            repo,
+            number,
+            comment,
+        } => {
+            handle_reopen(github_client, owner, repo, number, comment).await?;
             Ok(())
         }
 
@@ -104,14 +158,72 @@ pub async fn handle_issue_command(
             assignees,
             state,
             labels,
+            milestone,
+            add_label,
+            remove_label,
+            add_assignee,
+            remove_assignee,
         } => {
             let command_args = UpdateIssueArgs {
                 title,
                 body,
-                number,
                 state,
+                milestone,
+                labels: labels.map(|l| l.0),
+                assignees: assignees.map(|a| a.0),
+                add_labels: add_label.0,
+                remove_labels: remove_label.0,
+                add_assignees: add_assignee.0,
+                remove_assignees: remove_assignee.0,
             };
-            handle_update(github_client, owner, repo, labels, assignees, command_args).await?;
+            handle_update(github_client, owner, repo, number, command_args).await?;
+            Ok(())
+        }
+
+        IssueCommand::Lock {
+            owner,
+            repo,
+            number,
+            reason,
+        } => {
+            handle_lock(github_client, owner, repo, number, reason).await?;
+            Ok(())
+        }
+
+        IssueCommand::Unlock {
+            owner,
+            repo,
+            number,
+        } => {
+            handle_unlock(github_client, owner, repo, number).await?;
+            Ok(())
+        }
+
+        IssueCommand::Pin {
+            owner,
+            repo,
+            number,
+        } => {
+            handle_pin(github_client, owner, repo, number, true).await?;
+            Ok(())
+        }
+
+        IssueCommand::Unpin {
+            owner,
+            repo,
+            number,
+        } => {
+            handle_pin(github_client, owner, repo, number, false).await?;
+            Ok(())
+        }
+
+        IssueCommand::Transfer {
+            owner,
+            repo,
+            number,
+            to,
+        } => {
+            handle_transfer(github_client, owner, repo, number, to).await?;
             Ok(())
         }
     }
@@ -128,7 +240,8 @@ async fn handle_list(
         None => RepoInfo::new(Repo::Current, None, None)?,
     };
 
-    let list_issues = issues::get_list(&github_client, &repo_info, &command_args).await?;
+    let list_issues =
+        issues::get_list_with_reactions(&github_client, &repo_info, &command_args).await?;
 
     print_issues(list_issues, command_args.state, command_args.numb_of_page);
     Ok(())
@@ -138,13 +251,21 @@ async fn handle_get(
     github_client: Client,
     owner: Option<RepoOwner>,
     repo: Option<RepoName>,
-    number: i64,
+    number: Option<i64>,
 ) -> Result<(), Box<dyn Error>> {
     let repo_info = match owner {
         Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
         None => RepoInfo::new(Repo::Current, None, None)?,
     };
 
+    let number = match choose_issue_number(&github_client, &repo_info, number).await? {
+        Some(n) => n,
+        None => {
+            println!("Issue not choosed or not find");
+            return Ok(());
+        }
+    };
+
     let result = issues::get(&github_client, &repo_info, number).await?;
 
     let list_comments = comments::get_all(&github_client, &repo_info, &number).await?;
@@ -154,6 +275,33 @@ async fn handle_get(
     Ok(())
 }
 
+async fn handle_timeline(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    number: Option<i64>,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let number = match choose_issue_number(&github_client, &repo_info, number).await? {
+        Some(n) => n,
+        None => {
+            println!("Issue not choosed or not find");
+            return Ok(());
+        }
+    };
+
+    let result = issues::get(&github_client, &repo_info, number).await?;
+    let events = issues::get_timeline(&repo_info, number).await?;
+
+    print_issue(result)?;
+    print_timeline(events, number);
+    Ok(())
+}
+
 async fn handle_get_form_list(
     github_client: Client,
     owner: Option<RepoOwner>,
@@ -167,7 +315,7 @@ async fn handle_get_form_list(
 
     let list_issues = issues::get_list(&github_client, &repo_info, &command_args).await?;
 
-    let choosed_issue = choose_issue(list_issues)?;
+    let choosed_issue = choose_one(list_issues, Some(&repo_info))?;
 
     if let Some(ch_i) = choosed_issue {
         let list_comments = comments::get_all(&github_client, &repo_info, &ch_i.number).await?;
@@ -185,26 +333,51 @@ async fn handle_create(
     github_client: Client,
     owner: Option<RepoOwner>,
     repo: Option<RepoName>,
-    title: String,
-    body: String,
-    assignees: String,
-    labels: String,
+    command_args: CreateIssueArgs,
+    check_labels: bool,
 ) -> Result<(), Box<dyn Error>> {
     let repo_info = match owner {
         Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
         None => RepoInfo::new(Repo::Current, None, None)?,
     };
 
-    let labels_list: Vec<String> = labels.split(",").map(|s| s.to_string()).collect();
-    let assignees_list: Vec<String> = assignees.split(",").map(|s| s.to_string()).collect();
+    let labels_list: Vec<String> = command_args
+        .labels
+        .split(",")
+        .map(|s| s.to_string())
+        .collect();
+    let assignees_list: Vec<String> = command_args
+        .assignees
+        .split(",")
+        .map(|s| s.to_string())
+        .collect();
+
+    if check_labels {
+        let repo_labels = labels::get_all(&github_client, &repo_info).await?;
+        let unknown: Vec<&str> = labels_list
+            .iter()
+            .filter(|l| {
+                !l.is_empty() && !repo_labels.iter().any(|rl| rl.name.eq_ignore_ascii_case(l))
+            })
+            .map(|l| l.as_str())
+            .collect();
+
+        if !unknown.is_empty() {
+            return Err(Box::new(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("Labels not found in repo: {}", unknown.join(", ")),
+            )));
+        }
+    }
 
     let result = issues::create(
         &github_client,
         repo_info,
-        &title,
-        &body,
+        &command_args.title,
+        &command_args.body,
         &assignees_list,
         &labels_list,
+        command_args.milestone.as_ref(),
     )
     .await?;
 
@@ -216,17 +389,76 @@ async fn handle_close(
     github_client: Client,
     owner: Option<RepoOwner>,
     repo: Option<RepoName>,
-    number: i64,
+    number: Option<i64>,
     comment: String,
+    reason: CloseReason,
+    duplicate_of: Option<i64>,
 ) -> Result<(), Box<dyn Error>> {
     let repo_info = match owner {
         Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
         None => RepoInfo::new(Repo::Current, None, None)?,
     };
 
-    let result = issues::close(&github_client, repo_info, &number, &comment).await?;
+    let (comment, reason) = match duplicate_of {
+        Some(original) if comment.is_empty() => {
+            (format!("Duplicate of #{original}"), CloseReason::Duplicate)
+        }
+        Some(original) => (
+            format!("Duplicate of #{original}\n\n{comment}"),
+            CloseReason::Duplicate,
+        ),
+        None => (comment, reason),
+    };
 
-    println!("{result}");
+    let numbers = match number {
+        Some(n) => vec![n],
+        None => {
+            choose_issue_numbers(&github_client, &repo_info, IssuesListState::Open, true).await?
+        }
+    };
+
+    if numbers.is_empty() {
+        println!("Issue not choosed or not find");
+    }
+
+    for number in numbers {
+        let result = issues::close(&github_client, &repo_info, &number, &comment, &reason)
+            .await
+            .map_err(|er| format!("issue {number}: {er}"))?;
+        println!("Issue {number}: {result}");
+    }
+    Ok(())
+}
+
+async fn handle_reopen(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    number: Option<i64>,
+    comment: String,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let numbers = match number {
+        Some(n) => vec![n],
+        None => {
+            choose_issue_numbers(&github_client, &repo_info, IssuesListState::Closed, true).await?
+        }
+    };
+
+    if numbers.is_empty() {
+        println!("Issue not choosed or not find");
+    }
+
+    for number in numbers {
+        let result = issues::reopen(&github_client, &repo_info, &number, &comment)
+            .await
+            .map_err(|er| format!("issue {number}: {er}"))?;
+        println!("Issue {number}: {result}");
+    }
     Ok(())
 }
 
@@ -234,8 +466,7 @@ async fn handle_update(
     github_client: Client,
     owner: Option<RepoOwner>,
     repo: Option<RepoName>,
-    labels: Option<String>,
-    assignees: Option<String>,
+    number: Option<i64>,
     command_args: UpdateIssueArgs,
 ) -> Result<(), Box<dyn Error>> {
     let repo_info = match owner {
@@ -243,24 +474,168 @@ async fn handle_update(
         None => RepoInfo::new(Repo::Current, None, None)?,
     };
 
-    let labels_list: Vec<String> = match labels {
-        Some(l) => l.split(",").map(|s| s.to_string()).collect(),
-        None => Vec::new(),
+    let number = match choose_issue_number(&github_client, &repo_info, number).await? {
+        Some(n) => n,
+        None => {
+            println!("Issue not choosed or not find");
+            return Ok(());
+        }
     };
-    let assignees_list: Vec<String> = match assignees {
-        Some(a) => a.split(",").map(|s| s.to_string()).collect(),
-        None => Vec::new(),
+
+    let result = issues::update(&github_client, &repo_info, number, command_args).await?;
+
+    println!("{result}");
+    Ok(())
+}
+
+async fn handle_lock(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    number: Option<i64>,
+    reason: Option<LockReasons>,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
     };
 
-    let result = issues::update(
-        &github_client,
-        repo_info,
-        command_args,
-        &assignees_list,
-        &labels_list,
-    )
-    .await?;
+    let number = match choose_issue_number(&github_client, &repo_info, number).await? {
+        Some(n) => n,
+        None => {
+            println!("Issue not choosed or not find");
+            return Ok(());
+        }
+    };
+
+    let result = issues::lock(&github_client, &repo_info, number, reason.map(|r| r.0)).await?;
+
+    println!("{result}");
+    Ok(())
+}
+
+async fn handle_unlock(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    number: Option<i64>,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let number = match choose_issue_number(&github_client, &repo_info, number).await? {
+        Some(n) => n,
+        None => {
+            println!("Issue not choosed or not find");
+            return Ok(());
+        }
+    };
+
+    let result = issues::unlock(&github_client, &repo_info, number).await?;
 
     println!("{result}");
     Ok(())
 }
+
+async fn handle_pin(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    number: Option<i64>,
+    pinned: bool,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let number = match choose_issue_number(&github_client, &repo_info, number).await? {
+        Some(n) => n,
+        None => {
+            println!("Issue not choosed or not find");
+            return Ok(());
+        }
+    };
+
+    let result = issues::set_pinned(&github_client, &repo_info, number, pinned).await?;
+
+    println!("{result}");
+    Ok(())
+}
+
+async fn handle_transfer(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    number: Option<i64>,
+    to: RepoInfo,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let number = match choose_issue_number(&github_client, &repo_info, number).await? {
+        Some(n) => n,
+        None => {
+            println!("Issue not choosed or not find");
+            return Ok(());
+        }
+    };
+
+    let result = issues::transfer(&github_client, &repo_info, number, &to).await?;
+
+    print_url(result, "Transferred issue url");
+    Ok(())
+}
+
+/// Use given issue number or pick one from open issues list
+async fn choose_issue_number(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    number: Option<i64>,
+) -> Result<Option<i64>, Box<dyn Error>> {
+    match number {
+        Some(n) => Ok(Some(n)),
+        None => Ok(
+            choose_issue_numbers(github_client, repo_info, IssuesListState::Open, false)
+                .await?
+                .pop(),
+        ),
+    }
+}
+
+/// Pick numbers of issues from issues list with state
+async fn choose_issue_numbers(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    state: IssuesListState,
+    multi: bool,
+) -> Result<Vec<i64>, Box<dyn Error>> {
+    let command_args = ListIssueArgs {
+        creator: String::new(),
+        assignee: String::new(),
+        state: IssuesListStates(state),
+        labels: String::new(),
+        milestone: None,
+        sort: IssuesListSorts::Created,
+        numb_of_page: 1,
+        iss_on_page: 100,
+    };
+
+    let list_issues: Vec<IssueSimple> = issues::get_list(github_client, repo_info, &command_args)
+        .await?
+        .into_iter()
+        .filter(|iss| iss.pull_request.is_none())
+        .collect();
+
+    let choosed_issues = if multi {
+        choose_many(list_issues, Some(repo_info))?
+    } else {
+        choose_one(list_issues, Some(repo_info))?.into_iter().collect()
+    };
+
+    Ok(choosed_issues.iter().map(|iss| iss.number).collect())
+}
@@ -0,0 +1,264 @@
+use std::error::Error;
+use std::path::PathBuf;
+
+use octorust::{self, Client};
+
+use crate::cli_in::label_command::LabelCommand;
+use crate::cli_in::set_vars::LabelColor;
+use crate::cli_out::fuzzy_select::{choose_many, choose_one};
+use crate::cli_out::print_in_cli::{print_label_changes, print_labels};
+use crate::git_utils::labels::{self, LabelSpec};
+use crate::git_utils::repo_info::{Repo, RepoInfo, RepoName, RepoOwner};
+
+pub async fn handle_label_command(
+    github_client: Client,
+    subcommand: LabelCommand,
+) -> Result<(), Box<dyn Error>> {
+    match subcommand {
+        LabelCommand::List { owner, repo } => {
+            handle_list(github_client, owner, repo).await?;
+            Ok(())
+        }
+
+        LabelCommand::Create {
+            owner,
+            repo,
+            name,
+            color,
+            description,
+        } => {
+            let label = LabelSpec {
+                name,
+                color: color.0,
+                description,
+            };
+            handle_create(github_client, owner, repo, label).await?;
+            Ok(())
+        }
+
+        LabelCommand::Edit {
+            owner,
+            repo,
+            name,
+            new_name,
+            color,
+            description,
+        } => {
+            handle_edit(
+                github_client,
+                owner,
+                repo,
+                name,
+                new_name,
+                color,
+                description,
+            )
+            .await?;
+            Ok(())
+        }
+
+        LabelCommand::Delete { owner, repo, name } => {
+            handle_delete(github_client, owner, repo, name).await?;
+            Ok(())
+        }
+
+        LabelCommand::Clone {
+            owner,
+            repo,
+            from,
+            prune,
+            dry_run,
+        } => {
+            handle_clone(github_client, owner, repo, from, prune, dry_run).await?;
+            Ok(())
+        }
+
+        LabelCommand::Sync {
+            owner,
+            repo,
+            file,
+            prune,
+            dry_run,
+        } => {
+            handle_sync(github_client, owner, repo, file, prune, dry_run).await?;
+            Ok(())
+        }
+    }
+}
+
+async fn handle_list(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let list_labels = labels::get_all(&github_client, &repo_info).await?;
+
+    print_labels(
+        list_labels,
+        format!("{}/{}", repo_info.get_owner(), repo_info.get_name()),
+    );
+    Ok(())
+}
+
+async fn handle_create(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    label: LabelSpec,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let result = labels::create(&github_client, &repo_info, &label).await?;
+
+    println!("{result}");
+    Ok(())
+}
+
+async fn handle_edit(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    name: Option<String>,
+    new_name: Option<String>,
+    color: Option<LabelColor>,
+    description: Option<String>,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let name = match name {
+        Some(n) => n,
+        None => {
+            let list_labels = labels::get_all(&github_client, &repo_info).await?;
+            match choose_one(list_labels, Some(&repo_info))? {
+                Some(l) => l.name,
+                None => {
+                    println!("Label not choosed or not find");
+                    return Ok(());
+                }
+            }
+        }
+    };
+
+    let result = labels::update(
+        &github_client,
+        &repo_info,
+        &name,
+        &new_name.unwrap_or_default(),
+        &color.map(|c| c.0).unwrap_or_default(),
+        &description.unwrap_or_default(),
+    )
+    .await?;
+
+    println!("{result}");
+    Ok(())
+}
+
+async fn handle_delete(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    name: Option<String>,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let names = match name {
+        Some(n) => vec![n],
+        None => {
+            let list_labels = labels::get_all(&github_client, &repo_info).await?;
+            choose_many(list_labels, Some(&repo_info))?
+                .into_iter()
+                .map(|l| l.name)
+                .collect()
+        }
+    };
+
+    if names.is_empty() {
+        println!("Label not choosed or not find");
+    }
+
+    for name in names {
+        let result = labels::delete(&github_client, &repo_info, &name).await?;
+        println!("Label {name}: {result}");
+    }
+    Ok(())
+}
+
+async fn handle_clone(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    from: RepoInfo,
+    prune: bool,
+    dry_run: bool,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let wanted: Vec<LabelSpec> = labels::get_all(&github_client, &from)
+        .await?
+        .into_iter()
+        .map(LabelSpec::from)
+        .collect();
+
+    apply_labels(&github_client, &repo_info, &wanted, prune, dry_run).await
+}
+
+async fn handle_sync(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    file: PathBuf,
+    prune: bool,
+    dry_run: bool,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let wanted = labels::read_file(&file)?;
+
+    apply_labels(&github_client, &repo_info, &wanted, prune, dry_run).await
+}
+
+/// Show difference between repo labels and wanted labels, then apply it if not dry run
+async fn apply_labels(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    wanted: &[LabelSpec],
+    prune: bool,
+    dry_run: bool,
+) -> Result<(), Box<dyn Error>> {
+    let current: Vec<LabelSpec> = labels::get_all(github_client, repo_info)
+        .await?
+        .into_iter()
+        .map(LabelSpec::from)
+        .collect();
+
+    let changes = labels::plan(&current, wanted, prune);
+    print_label_changes(&changes);
+
+    if dry_run || changes.is_empty() {
+        return Ok(());
+    }
+
+    labels::apply(github_client, repo_info, &changes).await?;
+    println!("Success");
+    Ok(())
+}
@@ -0,0 +1,199 @@
+use std::error::Error;
+
+use octorust::types::{IssuesListState, State};
+use octorust::{self, Client};
+
+use crate::cli_in::milestone_command::MilestoneCommand;
+use crate::cli_in::set_vars::{DueDate, IssuesListStates, MilestoneRef, States};
+use crate::cli_out::fuzzy_select::choose_one;
+use crate::cli_out::print_in_cli::print_milestones;
+use crate::cli_parse::entities::EditMilestoneArgs;
+use crate::git_utils::milestones;
+use crate::git_utils::repo_info::{Repo, RepoInfo, RepoName, RepoOwner};
+
+pub async fn handle_milestone_command(
+    github_client: Client,
+    subcommand: MilestoneCommand,
+) -> Result<(), Box<dyn Error>> {
+    match subcommand {
+        MilestoneCommand::List { owner, repo, state } => {
+            handle_list(github_client, owner, repo, state).await?;
+            Ok(())
+        }
+
+        MilestoneCommand::Create {
+            owner,
+            repo,
+            title,
+            description,
+            due_on,
+        } => {
+            handle_create(github_client, owner, repo, title, description, due_on).await?;
+            Ok(())
+        }
+
+        MilestoneCommand::Edit {
+            owner,
+            repo,
+            milestone,
+            title,
+            description,
+            due_on,
+            state,
+        } => {
+            let command_args = EditMilestoneArgs {
+                title,
+                description,
+                due_on,
+                state,
+            };
+            handle_edit(github_client, owner, repo, milestone, command_args).await?;
+            Ok(())
+        }
+
+        MilestoneCommand::Close {
+            owner,
+            repo,
+            milestone,
+        } => {
+            let command_args = EditMilestoneArgs {
+                title: None,
+                description: None,
+                due_on: None,
+                state: Some(States(State::Closed)),
+            };
+            handle_edit(github_client, owner, repo, milestone, command_args).await?;
+            Ok(())
+        }
+
+        MilestoneCommand::Delete {
+            owner,
+            repo,
+            milestone,
+        } => {
+            handle_delete(github_client, owner, repo, milestone).await?;
+            Ok(())
+        }
+    }
+}
+
+async fn handle_list(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    state: IssuesListStates,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let list_milestones = milestones::get_all(&github_client, &repo_info, state.0.clone()).await?;
+
+    print_milestones(list_milestones, state);
+    Ok(())
+}
+
+async fn handle_create(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    title: String,
+    description: String,
+    due_on: Option<DueDate>,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let result = milestones::create(
+        &github_client,
+        &repo_info,
+        &title,
+        &description,
+        due_on.map(|d| d.0),
+    )
+    .await?;
+
+    println!("{result}");
+    Ok(())
+}
+
+async fn handle_edit(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    milestone: Option<MilestoneRef>,
+    command_args: EditMilestoneArgs,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let number = match choose_milestone_number(&github_client, &repo_info, milestone).await? {
+        Some(n) => n,
+        None => {
+            println!("Milestone not choosed or not find");
+            return Ok(());
+        }
+    };
+
+    let result = milestones::update(
+        &github_client,
+        &repo_info,
+        number,
+        &command_args.title.unwrap_or_default(),
+        &command_args.description.unwrap_or_default(),
+        command_args.due_on.map(|d| d.0),
+        command_args.state.map(|s| s.0),
+    )
+    .await?;
+
+    println!("{result}");
+    Ok(())
+}
+
+async fn handle_delete(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    milestone: Option<MilestoneRef>,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let number = match choose_milestone_number(&github_client, &repo_info, milestone).await? {
+        Some(n) => n,
+        None => {
+            println!("Milestone not choosed or not find");
+            return Ok(());
+        }
+    };
+
+    let result = milestones::delete(&github_client, &repo_info, number).await?;
+
+    println!("{result}");
+    Ok(())
+}
+
+/// Resolve milestone title to number or pick milestone from list if not set
+async fn choose_milestone_number(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    milestone: Option<MilestoneRef>,
+) -> Result<Option<i64>, Box<dyn Error>> {
+    match milestone {
+        Some(m) => Ok(Some(
+            milestones::resolve(github_client, repo_info, &m).await?,
+        )),
+        None => {
+            let list_milestones =
+                milestones::get_all(github_client, repo_info, IssuesListState::All).await?;
+            Ok(choose_one(list_milestones, Some(repo_info))?.map(|m| m.number))
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::time::Duration;
+
+use chrono::Utc;
+use octorust::types::Thread;
+use octorust::{self, Client};
+
+use crate::cli_in::notification_command::NotificationCommand;
+use crate::cli_in::set_vars::NotificationReason;
+use crate::cli_out::fuzzy_select::{choose_many, choose_one};
+use crate::cli_out::print_in_cli::{print_comments, print_issue_or_pull, print_notifications};
+use crate::git_utils::comments;
+use crate::git_utils::issues;
+use crate::git_utils::notifications;
+use crate::git_utils::repo_info::RepoInfo;
+
+pub async fn handle_notification_command(
+    github_client: Client,
+    subcommand: NotificationCommand,
+) -> Result<(), Box<dyn Error>> {
+    match subcommand {
+        NotificationCommand::List {
+            repo,
+            reason,
+            participating,
+            all,
+            watch,
+        } => {
+            if watch {
+                handle_watch(github_client, repo, reason, participating).await?;
+            } else {
+                handle_list(github_client, repo, reason, participating, all).await?;
+            }
+            Ok(())
+        }
+
+        NotificationCommand::Read { id, all, repo } => {
+            handle_read(github_client, id, all, repo).await?;
+            Ok(())
+        }
+
+        NotificationCommand::Mute { id } => {
+            handle_mute(github_client, id).await?;
+            Ok(())
+        }
+
+        NotificationCommand::Open { id } => {
+            handle_open(github_client, id).await?;
+            Ok(())
+        }
+    }
+}
+
+async fn handle_list(
+    github_client: Client,
+    repo: Option<RepoInfo>,
+    reason: Option<NotificationReason>,
+    participating: bool,
+    all: bool,
+) -> Result<(), Box<dyn Error>> {
+    let threads = notifications::get_all(&github_client, repo.as_ref(), all, participating).await?;
+
+    let threads: Vec<Thread> = threads
+        .into_iter()
+        .filter(|t| has_reason(t, &reason))
+        .collect();
+
+    println!(" Found {} notifications", threads.len());
+    print_notifications(&threads);
+    Ok(())
+}
+
+// Poll notifications with interval given by github and print only new or updated ones
+async fn handle_watch(
+    github_client: Client,
+    repo: Option<RepoInfo>,
+    reason: Option<NotificationReason>,
+    participating: bool,
+) -> Result<(), Box<dyn Error>> {
+    let since = Utc::now();
+    let mut seen: HashMap<String, String> = HashMap::new();
+
+    println!(" Waiting for new notifications (Ctrl+C to stop)");
+    loop {
+        let (threads, poll_interval) =
+            notifications::get_new(&github_client, repo.as_ref(), participating, since).await?;
+
+        let new_threads: Vec<Thread> = threads
+            .into_iter()
+            .filter(|t| has_reason(t, &reason))
+            .filter(|t| seen.get(&t.id) != Some(&t.updated_at))
+            .collect();
+
+        for thread in &new_threads {
+            seen.insert(thread.id.clone(), thread.updated_at.clone());
+        }
+        print_notifications(&new_threads);
+
+        tokio::time::sleep(Duration::from_secs(poll_interval)).await;
+    }
+}
+
+async fn handle_read(
+    github_client: Client,
+    id: Option<i64>,
+    all: bool,
+    repo: Option<RepoInfo>,
+) -> Result<(), Box<dyn Error>> {
+    if all {
+        let result = notifications::mark_all_read(repo.as_ref()).await?;
+        println!("{result}");
+        return Ok(());
+    }
+
+    let ids = choose_thread_ids(&github_client, id, true).await?;
+    if ids.is_empty() {
+        println!("Notification not choosed or not find");
+        return Ok(());
+    }
+
+    for id in ids {
+        let result = notifications::mark_read(&github_client, id).await?;
+        println!("{id}: {result}");
+    }
+    Ok(())
+}
+
+async fn handle_mute(github_client: Client, id: Option<i64>) -> Result<(), Box<dyn Error>> {
+    let id = match choose_thread_ids(&github_client, id, false).await?.pop() {
+        Some(id) => id,
+        None => {
+            println!("Notification not choosed or not find");
+            return Ok(());
+        }
+    };
+
+    let result = notifications::mute(&github_client, id).await?;
+
+    println!("{result}");
+    Ok(())
+}
+
+async fn handle_open(github_client: Client, id: Option<i64>) -> Result<(), Box<dyn Error>> {
+    let id = match choose_thread_ids(&github_client, id, false).await?.pop() {
+        Some(id) => id,
+        None => {
+            println!("Notification not choosed or not find");
+            return Ok(());
+        }
+    };
+
+    let thread = notifications::get(&github_client, id).await?;
+    let (repo_info, number) = notifications::subject_issue(&thread)?;
+
+    let issue = issues::get(&github_client, &repo_info, number).await?;
+    let list_comments = comments::get_all(&github_client, &repo_info, &number).await?;
+
+    print_issue_or_pull(issue);
+    print_comments(list_comments)?;
+
+    notifications::mark_read(&github_client, id).await?;
+    Ok(())
+}
+
+fn has_reason(thread: &Thread, reason: &Option<NotificationReason>) -> bool {
+    match reason {
+        Some(r) => thread.reason == r.0,
+        None => true,
+    }
+}
+
+// Return given id or pick notifications from unread ones
+async fn choose_thread_ids(
+    github_client: &Client,
+    id: Option<i64>,
+    multi: bool,
+) -> Result<Vec<i64>, Box<dyn Error>> {
+    if let Some(id) = id {
+        return Ok(vec![id]);
+    }
+
+    let threads = notifications::get_all(github_client, None, false, false).await?;
+
+    // Every notification shows comments from its own repo
+    let choosed = if multi {
+        choose_many(threads, None)?
+    } else {
+        choose_one(threads, None)?.into_iter().collect()
+    };
+
+    Ok(choosed.iter().filter_map(|t| t.id.parse().ok()).collect())
+}
@@ -0,0 +1,168 @@
+use std::error::Error;
+use std::path::PathBuf;
+
+use octorust::{self, Client};
+
+use crate::cli_in::repo_command::{ProtectionCommand, RulesetCommand};
+use crate::cli_out::print_in_cli::{print_field_changes, print_ruleset_changes, print_rulesets};
+use crate::git_utils::protection;
+use crate::git_utils::repo_info::{Repo, RepoInfo, RepoName, RepoOwner};
+use crate::git_utils::repos;
+use crate::git_utils::rulesets;
+use crate::git_utils::spec_diff;
+
+pub async fn handle_protection_command(
+    github_client: Client,
+    subcommand: ProtectionCommand,
+) -> Result<(), Box<dyn Error>> {
+    match subcommand {
+        ProtectionCommand::Get {
+            owner,
+            repo,
+            branch,
+        } => {
+            handle_get(github_client, owner, repo, branch).await?;
+            Ok(())
+        }
+
+        ProtectionCommand::Set {
+            owner,
+            repo,
+            branch,
+            file,
+            dry_run,
+        } => {
+            handle_set(github_client, owner, repo, branch, file, dry_run).await?;
+            Ok(())
+        }
+    }
+}
+
+pub async fn handle_ruleset_command(subcommand: RulesetCommand) -> Result<(), Box<dyn Error>> {
+    match subcommand {
+        RulesetCommand::List { owner, repo } => {
+            handle_list_rulesets(owner, repo).await?;
+            Ok(())
+        }
+
+        RulesetCommand::Apply {
+            owner,
+            repo,
+            file,
+            prune,
+            dry_run,
+        } => {
+            handle_apply_rulesets(owner, repo, file, prune, dry_run).await?;
+            Ok(())
+        }
+    }
+}
+
+async fn handle_get(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    branch: Option<String>,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+    let branch = match branch {
+        Some(b) => b,
+        None => repos::get_default_branch(&github_client, &repo_info).await?,
+    };
+
+    let spec = protection::get(&repo_info, &branch).await?;
+
+    print!("{}", serde_yaml::to_string(&spec)?);
+    Ok(())
+}
+
+async fn handle_set(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    branch: Option<String>,
+    file: PathBuf,
+    dry_run: bool,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+    let branch = match branch {
+        Some(b) => b,
+        None => repos::get_default_branch(&github_client, &repo_info).await?,
+    };
+
+    let wanted = protection::read_file(&file)?;
+    let current = protection::get(&repo_info, &branch).await?;
+
+    let changes = spec_diff::diff(
+        &serde_json::to_value(&current)?,
+        &serde_json::to_value(&wanted)?,
+    );
+    if changes.is_empty() {
+        println!(" Protection of {branch} is up to date");
+        return Ok(());
+    }
+
+    println!(" Protection of {branch}:");
+    print_field_changes(&changes);
+    if dry_run {
+        return Ok(());
+    }
+
+    let result = protection::set(&repo_info, &branch, &current, &wanted).await?;
+    println!("{result}");
+    Ok(())
+}
+
+async fn handle_list_rulesets(
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let list_rulesets = rulesets::get_all(&repo_info).await?;
+
+    print_rulesets(&list_rulesets);
+    Ok(())
+}
+
+async fn handle_apply_rulesets(
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    file: PathBuf,
+    prune: bool,
+    dry_run: bool,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let wanted = rulesets::read_file(&file)?;
+
+    // List has only short info, rules are compared with full rulesets
+    let mut current = Vec::new();
+    for ruleset in rulesets::get_all(&repo_info).await? {
+        let id = ruleset["id"].as_i64().unwrap_or_default();
+        current.push(rulesets::get(&repo_info, id).await?);
+    }
+
+    let changes = rulesets::plan(&current, &wanted, prune);
+    print_ruleset_changes(&changes);
+
+    if dry_run || changes.is_empty() {
+        return Ok(());
+    }
+
+    rulesets::apply(&repo_info, &changes).await?;
+    println!("Success");
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use std::error::Error;
+
+use octorust::types::Content;
+use octorust::{self, Client};
+
+use crate::cli_in::reaction_command::ReactionCommand;
+use crate::cli_in::set_vars::{ReactionTarget, Reactions};
+use crate::cli_out::print_in_cli::print_reactions;
+use crate::git_utils::reactions;
+use crate::git_utils::repo_info::{Repo, RepoInfo, RepoName, RepoOwner};
+
+pub async fn handle_reaction_command(
+    github_client: Client,
+    subcommand: ReactionCommand,
+) -> Result<(), Box<dyn Error>> {
+    match subcommand {
+        ReactionCommand::List {
+            owner,
+            repo,
+            target,
+            id,
+        } => {
+            handle_list(github_client, owner, repo, target, id).await?;
+            Ok(())
+        }
+
+        ReactionCommand::Add {
+            owner,
+            repo,
+            target,
+            id,
+            content,
+        } => {
+            handle_add(github_client, owner, repo, target, id, content).await?;
+            Ok(())
+        }
+
+        ReactionCommand::Remove {
+            owner,
+            repo,
+            target,
+            id,
+            content,
+        } => {
+            handle_remove(github_client, owner, repo, target, id, content).await?;
+            Ok(())
+        }
+    }
+}
+
+async fn handle_list(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    target: ReactionTarget,
+    id: i64,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let list_reactions =
+        reactions::get_all(&github_client, &repo_info, &target, id, Content::Noop).await?;
+
+    print_reactions(list_reactions);
+    Ok(())
+}
+
+async fn handle_add(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    target: ReactionTarget,
+    id: i64,
+    content: Reactions,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let result = reactions::create(&github_client, &repo_info, &target, id, content.0).await?;
+
+    println!("{result}");
+    Ok(())
+}
+
+async fn handle_remove(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    target: ReactionTarget,
+    id: i64,
+    content: Reactions,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let result = reactions::delete_own(&github_client, &repo_info, &target, id, content.0).await?;
+
+    println!("{result}");
+    Ok(())
+}
@@ -1,15 +1,29 @@
+use octorust::types::{Release, ReleaseAsset};
 use octorust::{self, Client};
 use std::error::Error;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
 
 use crate::cli_in::release_command::ReleaseCommand;
+use crate::cli_in::set_vars::BumpLevel;
+use crate::cli_out::fuzzy_select::choose_one;
+use crate::cli_out::print_in_cli::print_progress;
 use crate::cli_out::print_in_cli::print_release;
+use crate::cli_out::print_in_cli::print_releases;
 use crate::cli_out::print_in_cli::print_url;
-use crate::cli_parse::entities::CreateReleaseArgs;
+use crate::cli_parse::entities::{CreateReleaseArgs, EditReleaseArgs};
+use crate::git_utils::assets::{self, CHECKSUMS_NAME};
+use crate::git_utils::branches;
+use crate::git_utils::changelog;
+use crate::git_utils::local_repo;
 use crate::git_utils::releases;
 use crate::git_utils::repo_info::Repo;
 use crate::git_utils::repo_info::RepoInfo;
 use crate::git_utils::repo_info::RepoName;
 use crate::git_utils::repo_info::RepoOwner;
+use crate::git_utils::repos;
+use crate::git_utils::versions;
 
 pub async fn handle_release_command(
     github_client: Client,
@@ -25,8 +39,17 @@ pub async fn handle_release_command(
             draft,
             prerelease,
             tag_name,
+            from_manifest,
             target_commitish,
+            generate_notes,
+            previous_tag,
+            changelog_from,
         } => {
+            let tag_name = match tag_name {
+                Some(t) => t,
+                None if from_manifest => format!("v{}", local_repo::manifest_version()?),
+                None => String::new(),
+            };
             let command_args = CreateReleaseArgs {
                 body,
                 name,
@@ -36,7 +59,49 @@ pub async fn handle_release_command(
                 draft,
                 prerelease,
             };
-            handle_create(github_client, owner, repo, command_args).await?;
+            handle_create(
+                github_client,
+                owner,
+                repo,
+                command_args,
+                generate_notes,
+                previous_tag,
+                changelog_from,
+            )
+            .await?;
+            Ok(())
+        }
+
+        ReleaseCommand::Changelog {
+            owner,
+            repo,
+            from,
+            to,
+            title,
+            output,
+        } => {
+            handle_changelog(github_client, owner, repo, from, to, title, output).await?;
+            Ok(())
+        }
+
+        ReleaseCommand::Bump {
+            owner,
+            repo,
+            level,
+            preid,
+            generate_notes,
+            dry_run,
+        } => {
+            handle_bump(
+                github_client,
+                owner,
+                repo,
+                level,
+                preid,
+                generate_notes,
+                dry_run,
+            )
+            .await?;
             Ok(())
         }
 
@@ -54,16 +119,138 @@ pub async fn handle_release_command(
             handle_get_by_id(github_client, owner, repo, id).await?;
             Ok(())
         }
+
+        ReleaseCommand::List {
+            owner,
+            repo,
+            numb_of_page,
+            rel_on_page,
+        } => {
+            handle_list(github_client, owner, repo, numb_of_page, rel_on_page).await?;
+            Ok(())
+        }
+
+        ReleaseCommand::GetFromList { owner, repo } => {
+            handle_get_by_id(github_client, owner, repo, None).await?;
+            Ok(())
+        }
+
+        ReleaseCommand::Edit {
+            owner,
+            repo,
+            id,
+            name,
+            body,
+            draft,
+            publish,
+            prerelease,
+            make_latest,
+        } => {
+            let command_args = EditReleaseArgs {
+                name,
+                body,
+                draft: if publish { Some(false) } else { draft },
+                prerelease,
+                make_latest: make_latest.map(|m| m.0),
+            };
+            handle_edit(github_client, owner, repo, id, command_args).await?;
+            Ok(())
+        }
+
+        ReleaseCommand::Delete {
+            owner,
+            repo,
+            id,
+            cleanup_tag,
+        } => {
+            handle_delete(github_client, owner, repo, id, cleanup_tag).await?;
+            Ok(())
+        }
+
+        ReleaseCommand::Upload {
+            owner,
+            repo,
+            tag,
+            files,
+            clobber,
+            checksums,
+        } => {
+            handle_upload(github_client, owner, repo, tag, files, clobber, checksums).await?;
+            Ok(())
+        }
+
+        ReleaseCommand::Download {
+            owner,
+            repo,
+            tag,
+            pattern,
+            dir,
+            verify,
+        } => {
+            handle_download(github_client, owner, repo, tag, pattern, dir, verify).await?;
+            Ok(())
+        }
     }
 }
 
 async fn handle_create(
     github_client: Client,
-    owner: RepoOwner,
-    repo: RepoName,
-    command_args: CreateReleaseArgs,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    mut command_args: CreateReleaseArgs,
+    generate_notes: bool,
+    previous_tag: Option<String>,
+    changelog_from: Option<String>,
 ) -> Result<(), Box<dyn Error>> {
-    let repo_info: RepoInfo = RepoInfo::new(Repo::Input, Some(owner), Some(repo))?;
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => {
+            // Release of current repo should be made from committed state
+            if local_repo::is_dirty()? {
+                return Err(Box::new(io::Error::other(
+                    "Work tree has uncommitted changes, commit or stash them first",
+                )));
+            }
+            RepoInfo::new(Repo::Current, None, None)?
+        }
+    };
+
+    if command_args.target_commitish == "HEAD" {
+        command_args.target_commitish = local_repo::head_sha()?;
+    }
+    if releases::tag_exists(&github_client, &repo_info, &command_args.tag_name).await? {
+        return Err(Box::new(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!("Tag {} already exists", command_args.tag_name),
+        )));
+    }
+
+    let notes = if generate_notes {
+        releases::generate_notes(
+            &repo_info,
+            &command_args.tag_name,
+            &command_args.target_commitish,
+            previous_tag.as_deref(),
+        )
+        .await?
+    } else if let Some(from) = changelog_from {
+        // Tag doesn't exist yet, so changes are taken up to target
+        let to = match command_args.target_commitish.as_str() {
+            "" => repos::get_default_branch(&github_client, &repo_info).await?,
+            target => target.to_string(),
+        };
+        changelog::build(
+            &github_client,
+            &repo_info,
+            &from,
+            &to,
+            &command_args.tag_name,
+        )
+        .await?
+    } else {
+        String::new()
+    };
+    command_args.body = join_body(&command_args.body, &notes);
 
     let result = releases::create(&github_client, repo_info, command_args).await?;
 
@@ -71,6 +258,101 @@ async fn handle_create(
     Ok(())
 }
 
+async fn handle_changelog(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    from: String,
+    to: Option<String>,
+    title: Option<String>,
+    output: Option<PathBuf>,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let to = match to {
+        Some(t) => t,
+        None => repos::get_default_branch(&github_client, &repo_info).await?,
+    };
+    let title = title.unwrap_or_else(|| to.clone());
+
+    let text = changelog::build(&github_client, &repo_info, &from, &to, &title).await?;
+
+    match output {
+        Some(path) => {
+            prepend_changelog(&path, &text)?;
+            println!("Changelog written to {}", path.display());
+        }
+        None => println!("{text}"),
+    }
+    Ok(())
+}
+
+async fn handle_bump(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    level: BumpLevel,
+    preid: String,
+    generate_notes: bool,
+    dry_run: bool,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let latest = releases::get_latest(&github_client, repo_info.clone()).await?;
+    let (prefix, version) = versions::parse_tag(&latest.tag_name)?;
+
+    // Latest release is never prerelease, so already made prereleases are skipped by tags
+    let mut next = versions::bump(&version, &level, &preid)?;
+    let mut tag = format!("{prefix}{next}");
+    while releases::tag_exists(&github_client, &repo_info, &tag).await? {
+        if level != BumpLevel::Prerelease {
+            return Err(Box::new(io::Error::new(
+                ErrorKind::AlreadyExists,
+                format!("Tag {tag} already exists"),
+            )));
+        }
+        next = versions::bump(&next, &level, &preid)?;
+        tag = format!("{prefix}{next}");
+    }
+
+    let branch = repos::get_default_branch(&github_client, &repo_info).await?;
+    let sha = branches::get_sha(&github_client, &repo_info, &branch).await?;
+
+    println!("Latest release: {}", latest.tag_name);
+    println!("New tag: {tag} on {branch} ({sha})");
+    if dry_run {
+        println!("Dry run, nothing is created");
+        return Ok(());
+    }
+
+    let body = if generate_notes {
+        releases::generate_notes(&repo_info, &tag, &sha, Some(&latest.tag_name)).await?
+    } else {
+        String::new()
+    };
+
+    releases::create_tag(&github_client, &repo_info, &tag, &sha).await?;
+    let command_args = CreateReleaseArgs {
+        body,
+        name: tag.clone(),
+        discussion_category_name: String::new(),
+        tag_name: tag,
+        draft: None,
+        prerelease: Some(level == BumpLevel::Prerelease),
+        target_commitish: sha,
+    };
+    let result = releases::create(&github_client, repo_info, command_args).await?;
+
+    print_url(result, "New release");
+    Ok(())
+}
+
 async fn handle_get_latest(
     github_client: Client,
     owner: Option<RepoOwner>,
@@ -91,14 +373,23 @@ async fn handle_get_by_tag(
     github_client: Client,
     owner: Option<RepoOwner>,
     repo: Option<RepoName>,
-    tag: String,
+    tag: Option<String>,
 ) -> Result<(), Box<dyn Error>> {
     let repo_info = match owner {
         Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
         None => RepoInfo::new(Repo::Current, None, None)?,
     };
 
-    let result = releases::get_by_tag(&github_client, repo_info, tag).await?;
+    let result = match tag {
+        Some(t) => releases::get_by_tag(&github_client, repo_info, t).await?,
+        None => match choose_release(&github_client, &repo_info).await? {
+            Some(r) => r,
+            None => {
+                println!("Release not choosed or not find");
+                return Ok(());
+            }
+        },
+    };
 
     print_release(result);
     Ok(())
@@ -108,15 +399,281 @@ async fn handle_get_by_id(
     github_client: Client,
     owner: Option<RepoOwner>,
     repo: Option<RepoName>,
-    id: i64,
+    id: Option<i64>,
 ) -> Result<(), Box<dyn Error>> {
     let repo_info = match owner {
         Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
         None => RepoInfo::new(Repo::Current, None, None)?,
     };
 
-    let result = releases::get_by_id(&github_client, repo_info, id).await?;
+    let result = match id {
+        Some(i) => releases::get_by_id(&github_client, repo_info, i).await?,
+        None => match choose_release(&github_client, &repo_info).await? {
+            Some(r) => r,
+            None => {
+                println!("Release not choosed or not find");
+                return Ok(());
+            }
+        },
+    };
 
     print_release(result);
     Ok(())
 }
+
+async fn handle_list(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    numb_of_page: i64,
+    rel_on_page: i64,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let list_releases =
+        releases::get_page(&github_client, repo_info, numb_of_page, rel_on_page).await?;
+
+    print_releases(list_releases, numb_of_page);
+    Ok(())
+}
+
+async fn handle_edit(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    id: Option<i64>,
+    command_args: EditReleaseArgs,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let id = match id {
+        Some(i) => i,
+        None => match choose_release(&github_client, &repo_info).await? {
+            Some(r) => r.id,
+            None => {
+                println!("Release not choosed or not find");
+                return Ok(());
+            }
+        },
+    };
+
+    let result = releases::update(repo_info, id, command_args).await?;
+
+    print_url(result, "Updated release");
+    Ok(())
+}
+
+async fn handle_delete(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    id: Option<i64>,
+    cleanup_tag: bool,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let release = match id {
+        Some(i) => releases::get_by_id(&github_client, repo_info.clone(), i).await?,
+        None => match choose_release(&github_client, &repo_info).await? {
+            Some(r) => r,
+            None => {
+                println!("Release not choosed or not find");
+                return Ok(());
+            }
+        },
+    };
+
+    let result = releases::delete(&github_client, &repo_info, release.id).await?;
+    println!("Release {}: {}", release.tag_name, result);
+
+    if cleanup_tag {
+        let result = releases::delete_tag(&github_client, &repo_info, &release.tag_name).await?;
+        println!("Tag {}: {}", release.tag_name, result);
+    }
+    Ok(())
+}
+
+async fn handle_upload(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    tag: String,
+    files: Vec<PathBuf>,
+    clobber: bool,
+    checksums: bool,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let release = releases::get_by_tag(&github_client, repo_info.clone(), tag).await?;
+
+    let names = files
+        .iter()
+        .map(|f| assets::file_name(f.as_path()))
+        .collect::<Result<Vec<String>, io::Error>>()?;
+
+    // Check all files before upload, so nothing is uploaded if some asset exists
+    let existing: Vec<&ReleaseAsset> = release
+        .assets
+        .iter()
+        .filter(|a| names.contains(&a.name))
+        .collect();
+    if !existing.is_empty() && !clobber {
+        let existing_names: Vec<&str> = existing.iter().map(|a| a.name.as_str()).collect();
+        return Err(Box::new(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!(
+                "Assets already exist: {}. Use --clobber to replace them",
+                existing_names.join(", ")
+            ),
+        )));
+    }
+
+    for (index, (path, name)) in files.iter().zip(&names).enumerate() {
+        // Old asset is deleted just before upload, so failed upload loses only this one
+        if let Some(asset) = existing.iter().find(|a| a.name == *name) {
+            assets::delete(&github_client, &repo_info, asset.id).await?;
+        }
+
+        let label = format!("[{}/{}] {}", index + 1, files.len(), name);
+        assets::upload_file(&release, path, move |done, total| {
+            print_progress("Uploading", &label, done, total)
+        })
+        .await?;
+        println!();
+    }
+
+    if checksums {
+        let mut sums = assets::get_checksums(&release).await?;
+        for (path, name) in files.iter().zip(&names) {
+            sums.insert(name.clone(), assets::sha256_file(path)?);
+        }
+
+        if let Some(asset) = release.assets.iter().find(|a| a.name == CHECKSUMS_NAME) {
+            assets::delete(&github_client, &repo_info, asset.id).await?;
+        }
+        assets::upload(
+            &release,
+            CHECKSUMS_NAME,
+            "text/plain",
+            assets::format_checksums(&sums).into_bytes(),
+        )
+        .await?;
+        println!(" {CHECKSUMS_NAME} updated");
+    }
+
+    print_url(release.html_url, "Release");
+    Ok(())
+}
+
+async fn handle_download(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    tag: String,
+    pattern: String,
+    dir: PathBuf,
+    verify: bool,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let release = releases::get_by_tag(&github_client, repo_info, tag).await?;
+
+    let pattern = glob::Pattern::new(&pattern)?;
+    let to_download: Vec<&ReleaseAsset> = release
+        .assets
+        .iter()
+        .filter(|a| pattern.matches(&a.name))
+        .collect();
+    if to_download.is_empty() {
+        println!("Assets not find by pattern");
+        return Ok(());
+    }
+
+    let checksums = if verify {
+        let sums = assets::get_checksums(&release).await?;
+        if sums.is_empty() {
+            return Err(Box::new(io::Error::new(
+                ErrorKind::NotFound,
+                format!("Release has no {CHECKSUMS_NAME} asset"),
+            )));
+        }
+        Some(sums)
+    } else {
+        None
+    };
+
+    fs::create_dir_all(&dir)?;
+    for asset in to_download {
+        let path = assets::download(asset, &dir, |done, total| {
+            print_progress("Downloading", &asset.name, done, total)
+        })
+        .await?;
+        println!();
+
+        let sums = match &checksums {
+            Some(s) if asset.name != CHECKSUMS_NAME => s,
+            _ => continue,
+        };
+        match sums.get(&asset.name) {
+            Some(hash) if *hash == assets::sha256_file(&path)? => {
+                println!(" {}: checksum OK", asset.name)
+            }
+            Some(_) => {
+                return Err(Box::new(io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Checksum mismatch for {}", path.display()),
+                )))
+            }
+            None => println!(" {}: not in {CHECKSUMS_NAME}, not verified", asset.name),
+        }
+    }
+    Ok(())
+}
+
+/// Pick release from all releases in repo
+async fn choose_release(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+) -> Result<Option<Release>, Box<dyn Error>> {
+    let list_releases = releases::get_all(github_client, repo_info).await?;
+
+    choose_one(list_releases, Some(repo_info))
+}
+
+fn join_body(body: &str, notes: &str) -> String {
+    match (body.is_empty(), notes.is_empty()) {
+        (_, true) => body.to_string(),
+        (true, false) => notes.to_string(),
+        (false, false) => format!("{body}\n\n{notes}"),
+    }
+}
+
+// New entries go after `# Changelog` heading if file has it, else to the top of file
+fn prepend_changelog(path: &Path, text: &str) -> Result<(), io::Error> {
+    let old = match fs::read_to_string(path) {
+        Ok(t) => t,
+        Err(er) if er.kind() == ErrorKind::NotFound => "# Changelog\n".to_string(),
+        Err(er) => return Err(er),
+    };
+
+    let new = match old.strip_prefix("# Changelog") {
+        Some(rest) => format!("# Changelog\n\n{}\n{}", text, rest.trim_start()),
+        None => format!("{}\n{}", text, old),
+    };
+    fs::write(path, new.trim_end().to_string() + "\n")
+}
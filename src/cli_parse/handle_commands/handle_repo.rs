@@ -1,16 +1,34 @@
+use glob::Pattern;
+use octorust::types::{Order, ReposListOrgSort, ReposListOrgType};
 use octorust::{self, Client};
 use std::error::Error;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 
 use crate::cli_in::repo_command::RepoCommand;
+use crate::cli_in::set_vars::GitProtocol;
 use crate::cli_in::set_vars::Orders;
 use crate::cli_in::set_vars::ReposListOrgSorts;
 use crate::cli_in::set_vars::ReposListOrgTypes;
 use crate::cli_in::set_vars::ReposListUserTypes;
 use crate::cli_in::set_vars::Visibilities;
+use crate::cli_out::fuzzy_select::choose_one;
+use crate::cli_out::print_in_cli::print_repo;
+use crate::cli_out::print_in_cli::print_repo_changes;
 use crate::cli_out::print_in_cli::print_repos;
 use crate::cli_out::print_in_cli::print_url;
 use crate::cli_parse::entities::CreateRepoArgs;
 use crate::cli_parse::entities::CreateRepoFromTemplateArgs;
+use crate::cli_parse::entities::EditRepoArgs;
+use crate::cli_parse::handle_commands::handle_protection::{
+    handle_protection_command, handle_ruleset_command,
+};
+use crate::git_utils::local_repo;
+use crate::git_utils::provision;
+use crate::git_utils::releases;
 use crate::git_utils::repo_info::Repo;
 use crate::git_utils::repo_info::RepoInfo;
 use crate::git_utils::repo_info::{RepoName, RepoOwner};
@@ -163,6 +181,123 @@ pub async fn handle_repo_command(
             handle_get_all_from_user(github_client, owner, type_value, sort_value, order).await?;
             Ok(())
         }
+
+        RepoCommand::View { owner, repo } => {
+            handle_view(github_client, owner, repo).await?;
+            Ok(())
+        }
+
+        RepoCommand::Edit {
+            owner,
+            repo,
+            allow_auto_merge,
+            allow_merge_commit,
+            allow_rebase_merge,
+            allow_squash_merge,
+            delete_branch_on_merge,
+            has_issues,
+            has_projects,
+            has_wiki,
+            is_template,
+            private,
+            description,
+            homepage,
+            default_branch,
+            topics,
+        } => {
+            let command_args = EditRepoArgs {
+                allow_auto_merge,
+                allow_merge_commit,
+                allow_rebase_merge,
+                allow_squash_merge,
+                delete_branch_on_merge,
+                has_issues,
+                has_projects,
+                has_wiki,
+                is_template,
+                private,
+                description,
+                homepage,
+                default_branch,
+                topics: topics.map(|t| t.0),
+            };
+            handle_edit(owner, repo, command_args).await?;
+            Ok(())
+        }
+
+        RepoCommand::Rename {
+            owner,
+            repo,
+            new_name,
+        } => {
+            handle_rename(owner, repo, new_name).await?;
+            Ok(())
+        }
+
+        RepoCommand::Archive { owner, repo } => {
+            handle_set_archived(github_client, owner, repo, true).await?;
+            Ok(())
+        }
+
+        RepoCommand::Unarchive { owner, repo } => {
+            handle_set_archived(github_client, owner, repo, false).await?;
+            Ok(())
+        }
+
+        RepoCommand::Delete { owner, repo, yes } => {
+            handle_delete(github_client, owner, repo, yes).await?;
+            Ok(())
+        }
+
+        RepoCommand::Clone {
+            repo,
+            dir,
+            protocol,
+        } => {
+            handle_clone(github_client, repo, dir, protocol).await?;
+            Ok(())
+        }
+
+        RepoCommand::Sync {
+            owner,
+            repo,
+            branch,
+            fast_forward,
+        } => {
+            handle_sync(github_client, owner, repo, branch, fast_forward).await?;
+            Ok(())
+        }
+
+        RepoCommand::Protection { subcommand } => {
+            handle_protection_command(github_client, subcommand).await?;
+            Ok(())
+        }
+
+        RepoCommand::Ruleset { subcommand } => {
+            handle_ruleset_command(subcommand).await?;
+            Ok(())
+        }
+
+        RepoCommand::Plan { file, prune } => {
+            handle_plan(github_client, file, prune).await?;
+            Ok(())
+        }
+
+        RepoCommand::Apply { file, prune } => {
+            handle_apply(github_client, file, prune).await?;
+            Ok(())
+        }
+
+        RepoCommand::CloneAll {
+            org,
+            filter,
+            jobs,
+            dir,
+            protocol,
+        } => {
+            handle_clone_all(github_client, org, filter, jobs, dir, protocol).await?;
+            Ok(())
+        }
     }
 }
 
@@ -265,3 +400,273 @@ async fn handle_get_all_from_user(
     print_repos(result, owner, "user");
     Ok(())
 }
+
+async fn handle_view(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let result = repos::get(&github_client, &repo_info).await?;
+    // Repo without releases answers with 404
+    let latest_release = releases::get_latest(&github_client, repo_info).await.ok();
+
+    print_repo(result, latest_release);
+    Ok(())
+}
+
+async fn handle_edit(
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    command_args: EditRepoArgs,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let result = repos::update(&repo_info, command_args).await?;
+
+    print_url(result, "Edited repo");
+    Ok(())
+}
+
+async fn handle_rename(
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    new_name: RepoName,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let result = repos::rename(&repo_info, &new_name).await?;
+
+    print_url(result, "Renamed repo");
+    Ok(())
+}
+
+async fn handle_set_archived(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    archived: bool,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    let result = repos::set_archived(&github_client, &repo_info, archived).await?;
+
+    println!("{result}");
+    Ok(())
+}
+
+async fn handle_delete(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    yes: bool,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+    let full_name = format!("{}/{}", repo_info.get_owner(), repo_info.get_name());
+
+    if !yes {
+        print!("Type {full_name} to delete it: ");
+        io::stdout().flush()?;
+
+        let mut answer = String::new();
+        io::stdin().read_line(&mut answer)?;
+        if answer.trim() != full_name {
+            println!("Repo name doesn't match, nothing is deleted");
+            return Ok(());
+        }
+    }
+
+    let result = repos::delete(&github_client, &repo_info).await?;
+
+    println!("{full_name}: {result}");
+    Ok(())
+}
+
+async fn handle_clone(
+    github_client: Client,
+    repo_info: Option<RepoInfo>,
+    dir: Option<PathBuf>,
+    protocol: GitProtocol,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match repo_info {
+        Some(r) => r,
+        None => {
+            let list_repos = repos::get_all_for_authenticated_user().await?;
+            match choose_one(list_repos, None)? {
+                Some(r) => r.full_name.parse::<RepoInfo>()?,
+                None => {
+                    println!("Repo not choosed or not find");
+                    return Ok(());
+                }
+            }
+        }
+    };
+    let repo = repos::get(&github_client, &repo_info).await?;
+    let dir = dir.unwrap_or_else(|| PathBuf::from(repo_info.get_name()));
+
+    local_repo::clone(&local_repo::clone_url(&repo_info, &protocol), &dir)?;
+    println!("Cloned {} to {}", repo.full_name, dir.display());
+
+    if let Some(parent) = repo.parent {
+        let parent_info = parent.full_name.parse::<RepoInfo>()?;
+        local_repo::add_remote(
+            &dir,
+            "upstream",
+            &local_repo::clone_url(&parent_info, &protocol),
+        )?;
+        println!("Added upstream remote for {}", parent.full_name);
+    }
+    Ok(())
+}
+
+async fn handle_clone_all(
+    github_client: Client,
+    org: String,
+    filter: String,
+    jobs: u32,
+    dir: PathBuf,
+    protocol: GitProtocol,
+) -> Result<(), Box<dyn Error>> {
+    let pattern = Pattern::new(&filter)?;
+    let all_repos = repos::get_all_from_org(
+        &github_client,
+        &org,
+        Order::Asc,
+        ReposListOrgType::All,
+        ReposListOrgSort::FullName,
+    )
+    .await?;
+
+    // git runs in blocking tasks, semaphore limits how many of them run at the same time
+    let semaphore = Arc::new(Semaphore::new(jobs as usize));
+    let mut tasks = JoinSet::new();
+    for repo in all_repos.into_iter().filter(|r| pattern.matches(&r.name)) {
+        let repo_info = repo.full_name.parse::<RepoInfo>()?;
+        let url = local_repo::clone_url(&repo_info, &protocol);
+        let path = dir.join(&repo.name);
+        let permit = semaphore.clone().acquire_owned().await?;
+
+        tasks.spawn_blocking(move || {
+            let _permit = permit;
+            let result = if path.join(".git").exists() {
+                local_repo::pull_ff_only(&path).map(|_| "updated")
+            } else {
+                local_repo::clone(&url, &path).map(|_| "cloned")
+            };
+            (repo.full_name, result)
+        });
+    }
+
+    let mut failed = 0;
+    while let Some(task) = tasks.join_next().await {
+        match task? {
+            (name, Ok(action)) => println!(" {name}: {action}"),
+            (name, Err(er)) => {
+                failed += 1;
+                eprintln!(" {name}: {er}");
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(Box::new(io::Error::other(format!(
+            "{failed} repos were not cloned or updated"
+        ))));
+    }
+    Ok(())
+}
+
+async fn handle_sync(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    branch: Option<String>,
+    fast_forward: bool,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+    let branch = match branch {
+        Some(b) => b,
+        None => repos::get_default_branch(&github_client, &repo_info).await?,
+    };
+
+    // Local checkout is fast-forwarded from its `origin`, so it must be the synced fork
+    if fast_forward {
+        let local_info = RepoInfo::new(Repo::Current, None, None)?;
+        if !local_info
+            .get_owner()
+            .eq_ignore_ascii_case(&repo_info.get_owner())
+            || !local_info
+                .get_name()
+                .eq_ignore_ascii_case(&repo_info.get_name())
+        {
+            return Err(format!(
+                "Can't fast-forward: origin of current dir is {}/{}, not {}/{}",
+                local_info.get_owner(),
+                local_info.get_name(),
+                repo_info.get_owner(),
+                repo_info.get_name()
+            )
+            .into());
+        }
+    }
+
+    let result = repos::sync_fork(&repo_info, &branch).await?;
+    println!("{result}");
+
+    if fast_forward {
+        local_repo::fast_forward(&branch)?;
+        println!("Local branch {branch} is fast-forwarded");
+    }
+    Ok(())
+}
+
+async fn handle_plan(
+    github_client: Client,
+    file: PathBuf,
+    prune: bool,
+) -> Result<(), Box<dyn Error>> {
+    let manifest = provision::read_file(&file)?;
+
+    let changes = provision::plan(&github_client, &manifest, prune).await?;
+
+    print_repo_changes(&manifest.org, &changes);
+    Ok(())
+}
+
+async fn handle_apply(
+    github_client: Client,
+    file: PathBuf,
+    prune: bool,
+) -> Result<(), Box<dyn Error>> {
+    let manifest = provision::read_file(&file)?;
+
+    let changes = provision::plan(&github_client, &manifest, prune).await?;
+    print_repo_changes(&manifest.org, &changes);
+
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    provision::apply(&github_client, &manifest.org, &changes).await?;
+    println!("Success");
+    Ok(())
+}
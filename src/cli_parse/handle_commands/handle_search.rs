@@ -0,0 +1,191 @@
+use std::error::Error;
+
+use octorust::types::Order;
+
+use crate::cli_in::search_command::SearchCommand;
+use crate::cli_out::print_in_cli::{
+    print_found_code, print_found_issues, print_found_repos, print_found_users,
+};
+use crate::cli_parse::entities::SearchArgs;
+use crate::git_utils::repo_info::RepoInfo;
+use crate::git_utils::search::{self, build_query};
+
+pub async fn handle_search_command(subcommand: SearchCommand) -> Result<(), Box<dyn Error>> {
+    match subcommand {
+        SearchCommand::Issues {
+            query,
+            repo,
+            org,
+            author,
+            assignee,
+            label,
+            is,
+            sort,
+            order,
+            limit,
+        } => {
+            let mut qualifiers = issue_qualifiers("issue", repo, org, author, assignee);
+            qualifiers.extend(label.0.into_iter().map(|l| ("label", l)));
+            qualifiers.extend(is.0.into_iter().map(|i| ("is", i)));
+
+            let args = SearchArgs {
+                query: build_query(&query, &qualifiers),
+                sort: sort.0.to_string(),
+                order: order.0,
+                limit,
+            };
+            handle_issues(args).await?;
+            Ok(())
+        }
+
+        SearchCommand::Prs {
+            query,
+            repo,
+            org,
+            author,
+            assignee,
+            label,
+            is,
+            sort,
+            order,
+            limit,
+        } => {
+            let mut qualifiers = issue_qualifiers("pr", repo, org, author, assignee);
+            qualifiers.extend(label.0.into_iter().map(|l| ("label", l)));
+            qualifiers.extend(is.0.into_iter().map(|i| ("is", i)));
+
+            let args = SearchArgs {
+                query: build_query(&query, &qualifiers),
+                sort: sort.0.to_string(),
+                order: order.0,
+                limit,
+            };
+            handle_issues(args).await?;
+            Ok(())
+        }
+
+        SearchCommand::Code {
+            query,
+            repo,
+            org,
+            language,
+            extension,
+            limit,
+        } => {
+            let qualifiers = vec![
+                ("repo", repo_qualifier(repo)),
+                ("org", org),
+                ("language", language),
+                ("extension", extension),
+            ];
+
+            let args = SearchArgs {
+                query: build_query(&query, &qualifiers),
+                sort: String::new(),
+                order: Order::Noop,
+                limit,
+            };
+            handle_code(args).await?;
+            Ok(())
+        }
+
+        SearchCommand::Repos {
+            query,
+            user,
+            org,
+            language,
+            topic,
+            sort,
+            order,
+            limit,
+        } => {
+            let mut qualifiers = vec![("user", user), ("org", org), ("language", language)];
+            qualifiers.extend(topic.0.into_iter().map(|t| ("topic", t)));
+
+            let args = SearchArgs {
+                query: build_query(&query, &qualifiers),
+                sort: sort.0.to_string(),
+                order: order.0,
+                limit,
+            };
+            handle_repos(args).await?;
+            Ok(())
+        }
+
+        SearchCommand::Users {
+            query,
+            account_type,
+            location,
+            language,
+            sort,
+            order,
+            limit,
+        } => {
+            let qualifiers = vec![
+                ("type", account_type),
+                ("location", location),
+                ("language", language),
+            ];
+
+            let args = SearchArgs {
+                query: build_query(&query, &qualifiers),
+                sort: sort.0.to_string(),
+                order: order.0,
+                limit,
+            };
+            handle_users(args).await?;
+            Ok(())
+        }
+    }
+}
+
+fn issue_qualifiers(
+    kind: &str,
+    repo: Option<RepoInfo>,
+    org: String,
+    author: String,
+    assignee: String,
+) -> Vec<(&'static str, String)> {
+    vec![
+        ("is", kind.to_string()),
+        ("repo", repo_qualifier(repo)),
+        ("org", org),
+        ("author", author),
+        ("assignee", assignee),
+    ]
+}
+
+fn repo_qualifier(repo: Option<RepoInfo>) -> String {
+    match repo {
+        Some(r) => format!("{}/{}", r.get_owner(), r.get_name()),
+        None => String::new(),
+    }
+}
+
+async fn handle_issues(args: SearchArgs) -> Result<(), Box<dyn Error>> {
+    let result = search::issues(&args).await?;
+
+    print_found_issues(result);
+    Ok(())
+}
+
+async fn handle_code(args: SearchArgs) -> Result<(), Box<dyn Error>> {
+    let result = search::code(&args).await?;
+
+    print_found_code(result);
+    Ok(())
+}
+
+async fn handle_repos(args: SearchArgs) -> Result<(), Box<dyn Error>> {
+    let result = search::repos(&args).await?;
+
+    print_found_repos(result);
+    Ok(())
+}
+
+async fn handle_users(args: SearchArgs) -> Result<(), Box<dyn Error>> {
+    let result = search::users(&args).await?;
+
+    print_found_users(result);
+    Ok(())
+}
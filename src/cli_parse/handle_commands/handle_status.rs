@@ -0,0 +1,16 @@
+use std::error::Error;
+
+use octorust::{self, Client};
+
+use crate::cli_out::print_in_cli::print_status;
+use crate::git_utils::status;
+
+pub async fn handle_status_command(
+    github_client: Client,
+    limit: i64,
+) -> Result<(), Box<dyn Error>> {
+    let dashboard = status::get(&github_client, limit).await?;
+
+    print_status(dashboard);
+    Ok(())
+}
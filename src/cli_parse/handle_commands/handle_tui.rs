@@ -0,0 +1,128 @@
+use octorust::types::{Order, ReposListOrgSort, ReposListOrgType, ReposListUserType};
+use octorust::{self, Client};
+use std::error::Error;
+
+use crate::cli_in::set_vars::{GitProtocol, IssuesListSorts, IssuesListStates};
+use crate::cli_in::tui_command::TuiCommand;
+use crate::cli_parse::entities::ListIssueArgs;
+use crate::git_utils::repo_info::Repo;
+use crate::git_utils::repo_info::RepoInfo;
+use crate::git_utils::repo_info::{RepoName, RepoOwner};
+use crate::git_utils::repos;
+use crate::tui;
+
+pub async fn handle_tui_command(
+    github_client: Client,
+    subcommand: TuiCommand,
+) -> Result<(), Box<dyn Error>> {
+    match subcommand {
+        TuiCommand::Issues {
+            owner,
+            repo,
+            creator,
+            assignee,
+            state,
+            labels,
+            iss_on_page,
+        } => {
+            let command_args = ListIssueArgs {
+                creator,
+                assignee,
+                state,
+                labels,
+                milestone: None,
+                sort: IssuesListSorts::Created,
+                numb_of_page: 1,
+                iss_on_page,
+            };
+            handle_issues(github_client, owner, repo, command_args).await?;
+            Ok(())
+        }
+
+        TuiCommand::Prs {
+            owner,
+            repo,
+            state,
+            prs_on_page,
+        } => {
+            handle_prs(github_client, owner, repo, state, prs_on_page).await?;
+            Ok(())
+        }
+
+        TuiCommand::Repos {
+            org,
+            user,
+            protocol,
+        } => {
+            handle_repos(github_client, org, user, protocol).await?;
+            Ok(())
+        }
+    }
+}
+
+async fn handle_issues(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    command_args: ListIssueArgs,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    tui::issues::run(&github_client, repo_info, command_args).await?;
+    Ok(())
+}
+
+async fn handle_prs(
+    github_client: Client,
+    owner: Option<RepoOwner>,
+    repo: Option<RepoName>,
+    state: IssuesListStates,
+    prs_on_page: i64,
+) -> Result<(), Box<dyn Error>> {
+    let repo_info = match owner {
+        Some(_) => RepoInfo::new(Repo::Input, owner, repo)?,
+        None => RepoInfo::new(Repo::Current, None, None)?,
+    };
+
+    tui::prs::run(&github_client, repo_info, state.0, prs_on_page).await?;
+    Ok(())
+}
+
+async fn handle_repos(
+    github_client: Client,
+    org: Option<String>,
+    user: Option<String>,
+    protocol: GitProtocol,
+) -> Result<(), Box<dyn Error>> {
+    let (owner, all_repos) = match (org, user) {
+        (Some(org), _) => {
+            let all_repos = repos::get_all_from_org(
+                &github_client,
+                &org,
+                Order::Desc,
+                ReposListOrgType::All,
+                ReposListOrgSort::Pushed,
+            )
+            .await?;
+            (org, all_repos)
+        }
+        (None, Some(user)) => {
+            let all_repos = repos::get_all_from_user(
+                &github_client,
+                user.clone(),
+                ReposListUserType::All,
+                ReposListOrgSort::Pushed,
+                Order::Desc,
+            )
+            .await?;
+            (user, all_repos)
+        }
+        (None, None) => return Err("Set org or user".into()),
+    };
+
+    tui::repos::run(&github_client, owner, all_repos, protocol).await?;
+    Ok(())
+}
@@ -1,4 +1,13 @@
+pub mod handle_branch;
 pub mod handle_comment;
 pub mod handle_issue;
+pub mod handle_label;
+pub mod handle_milestone;
+pub mod handle_notification;
+pub mod handle_protection;
+pub mod handle_reaction;
 pub mod handle_release;
 pub mod handle_repo;
+pub mod handle_search;
+pub mod handle_status;
+pub mod handle_tui;
@@ -0,0 +1,176 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use futures_util::TryStreamExt;
+use octorust::types::{Release, ReleaseAsset};
+use octorust::Client;
+use reqwest::Body;
+use sha2::{Digest, Sha256};
+use tokio_util::io::ReaderStream;
+
+use crate::git_utils::repo_info::RepoInfo;
+use crate::git_utils::rest;
+
+// Name of asset with checksums of other assets, in `sha256sum` format
+pub const CHECKSUMS_NAME: &str = "SHA256SUMS";
+
+// Upload file as asset named like file, returns download url.
+// File is streamed, `on_progress` gets sent and total bytes
+pub async fn upload_file(
+    release: &Release,
+    path: &Path,
+    mut on_progress: impl FnMut(u64, u64) + Send + 'static,
+) -> Result<String, Box<dyn Error>> {
+    let name = file_name(path)?;
+    let content_type = mime_guess::from_path(path)
+        .first_or_octet_stream()
+        .to_string();
+
+    let file = tokio::fs::File::open(path).await?;
+    let total = file.metadata().await?.len();
+    let mut sent = 0;
+    let stream = ReaderStream::new(file).inspect_ok(move |chunk| {
+        sent += chunk.len() as u64;
+        on_progress(sent, total);
+    });
+
+    let asset = rest::upload(
+        &release.upload_url,
+        &name,
+        &content_type,
+        Body::wrap_stream(stream),
+        total,
+    )
+    .await?;
+    Ok(download_url(&asset))
+}
+
+pub async fn upload(
+    release: &Release,
+    name: &str,
+    content_type: &str,
+    body: Vec<u8>,
+) -> Result<String, Box<dyn Error>> {
+    let size = body.len() as u64;
+    let asset = rest::upload(&release.upload_url, name, content_type, body.into(), size).await?;
+
+    Ok(download_url(&asset))
+}
+
+fn download_url(asset: &serde_json::Value) -> String {
+    asset["browser_download_url"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string()
+}
+
+pub async fn delete(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    asset_id: i64,
+) -> Result<String, Box<dyn Error>> {
+    let result = github_client
+        .repos()
+        .delete_release_asset(&repo_info.get_owner(), &repo_info.get_name(), asset_id)
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Download asset in dir. File is written with `.part` suffix and renamed when download is finished
+pub async fn download(
+    asset: &ReleaseAsset,
+    dir: &Path,
+    on_progress: impl FnMut(u64, u64),
+) -> Result<PathBuf, Box<dyn Error>> {
+    let path = dir.join(&asset.name);
+    let part_path = dir.join(format!("{}.part", asset.name));
+
+    let mut file = File::create(&part_path)?;
+    if let Err(er) = rest::download(&asset.url, &mut file, on_progress).await {
+        fs::remove_file(&part_path)?;
+        return Err(er);
+    }
+    fs::rename(&part_path, &path)?;
+
+    Ok(path)
+}
+
+// Get checksums from release SHA256SUMS asset. Empty if release has no such asset
+pub async fn get_checksums(release: &Release) -> Result<BTreeMap<String, String>, Box<dyn Error>> {
+    let asset = match release.assets.iter().find(|a| a.name == CHECKSUMS_NAME) {
+        Some(a) => a,
+        None => return Ok(BTreeMap::new()),
+    };
+
+    let mut text = Vec::new();
+    rest::download(&asset.url, &mut text, |_, _| {}).await?;
+
+    Ok(parse_checksums(&String::from_utf8(text)?))
+}
+
+pub fn sha256_file(path: &Path) -> Result<String, io::Error> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher)?;
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+// Lines are `<hash>  <name>`, binary mode mark `*` before name is allowed
+pub fn parse_checksums(text: &str) -> BTreeMap<String, String> {
+    text.lines()
+        .filter_map(|line| {
+            let (hash, name) = line.trim().split_once(char::is_whitespace)?;
+            let name = name.trim_start().trim_start_matches('*');
+            Some((name.to_string(), hash.to_lowercase()))
+        })
+        .collect()
+}
+
+pub fn format_checksums(checksums: &BTreeMap<String, String>) -> String {
+    checksums
+        .iter()
+        .map(|(name, hash)| format!("{hash}  {name}\n"))
+        .collect()
+}
+
+pub fn file_name(path: &Path) -> Result<String, io::Error> {
+    match path.file_name().and_then(|n| n.to_str()) {
+        Some(name) => Ok(name.to_string()),
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Bad file path: {}", path.display()),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod assets_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("abc  app.tar.gz\n", "app.tar.gz", "abc")]
+    #[case("ABC *app.exe", "app.exe", "abc")]
+    #[case("\n\nabc app\n", "app", "abc")]
+    fn parse_checksums_test(#[case] text: &str, #[case] name: &str, #[case] hash: &str) {
+        let checksums = parse_checksums(text);
+
+        assert_eq!(checksums.len(), 1);
+        assert_eq!(checksums.get(name).map(String::as_str), Some(hash));
+    }
+
+    #[test]
+    fn format_checksums_test() {
+        let text = "111  a.zip\n222  b.zip\n";
+
+        assert_eq!(format_checksums(&parse_checksums(text)), text);
+    }
+}
@@ -0,0 +1,316 @@
+use std::error::Error;
+
+use octorust::types::{
+    CommitComparison, GitCreateRefRequest, ReposRenameBranchRequest, ShortBranch,
+};
+use octorust::Client;
+use serde_json::{json, Value};
+
+use crate::git_utils::repo_info::RepoInfo;
+use crate::git_utils::repos;
+use crate::git_utils::rest;
+
+// Branches are asked in graphql by pages of this size
+const BRANCHES_ON_PAGE: i64 = 50;
+
+pub struct BranchInfo {
+    pub name: String,
+    pub sha: String,
+    pub message: String,
+    pub date: String,
+    // Commits which branch has and default branch doesn't, and vice versa
+    pub ahead: i64,
+    pub behind: i64,
+    pub protected: bool,
+    // Merged pull request with last commit of branch as head
+    pub merged_pull: Option<i64>,
+}
+
+// Default branch name and all branches with their last commits compared to default branch
+pub async fn get_all(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+) -> Result<(String, Vec<BranchInfo>), Box<dyn Error>> {
+    let default_branch = repos::get_default_branch(github_client, repo_info).await?;
+
+    // `compare` is made from branch to default branch, so ahead and behind are swapped
+    let query =
+        "query($owner: String!, $name: String!, $default: String!, $first: Int!, $cursor: String) {
+        repository(owner: $owner, name: $name) {
+            refs(refPrefix: \"refs/heads/\", first: $first, after: $cursor) {
+                pageInfo { hasNextPage endCursor }
+                nodes {
+                    name
+                    target { ... on Commit { oid messageHeadline committedDate } }
+                    branchProtectionRule { pattern }
+                    compare(headRef: $default) { aheadBy behindBy }
+                    associatedPullRequests(states: MERGED, first: 5) { nodes { number headRefOid } }
+                }
+            }
+        }
+    }";
+
+    let mut branches = Vec::new();
+    let mut cursor = Value::Null;
+    loop {
+        let variables = json!({
+            "owner": repo_info.get_owner(),
+            "name": repo_info.get_name(),
+            "default": default_branch,
+            "first": BRANCHES_ON_PAGE,
+            "cursor": cursor,
+        });
+        let data = rest::graphql(query, variables).await?;
+        let refs = &data["repository"]["refs"];
+
+        for node in refs["nodes"].as_array().into_iter().flatten() {
+            branches.push(branch_info(node));
+        }
+
+        if !refs["pageInfo"]["hasNextPage"]
+            .as_bool()
+            .unwrap_or_default()
+        {
+            break;
+        }
+        cursor = refs["pageInfo"]["endCursor"].clone();
+    }
+
+    Ok((default_branch, branches))
+}
+
+// Branch names with last commit sha and protection, without comparing (cheaper than `get_all`)
+pub async fn get_short_list(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+) -> Result<Vec<ShortBranch>, Box<dyn Error>> {
+    let result = github_client
+        .repos()
+        .list_all_branches(&repo_info.get_owner(), &repo_info.get_name(), false)
+        .await;
+
+    match result {
+        Ok(b) => Ok(b.body),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Sha of last commit in branch
+pub async fn get_sha(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    branch: &str,
+) -> Result<String, Box<dyn Error>> {
+    let result = github_client
+        .git()
+        .get_ref(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            &format!("heads/{branch}"),
+        )
+        .await;
+
+    match result {
+        Ok(r) => Ok(r.body.object.sha),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Sha of commit by sha, branch or tag
+pub async fn resolve_sha(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    reference: &str,
+) -> Result<String, Box<dyn Error>> {
+    let result = github_client
+        .repos()
+        .get_commit(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            0,
+            0,
+            reference,
+        )
+        .await;
+
+    match result {
+        Ok(c) => Ok(c.body.sha),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+pub async fn create(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    name: &str,
+    sha: &str,
+) -> Result<String, Box<dyn Error>> {
+    let request = GitCreateRefRequest {
+        key: String::new(),
+        ref_: format!("refs/heads/{name}"),
+        sha: sha.to_string(),
+    };
+
+    let result = github_client
+        .git()
+        .create_ref(&repo_info.get_owner(), &repo_info.get_name(), &request)
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+pub async fn delete(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    name: &str,
+) -> Result<String, Box<dyn Error>> {
+    let result = github_client
+        .git()
+        .delete_ref(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            &format!("heads/{name}"),
+        )
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Github moves pull requests and protection rules to new name too
+pub async fn rename(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    name: &str,
+    new_name: &str,
+) -> Result<String, Box<dyn Error>> {
+    let request = ReposRenameBranchRequest {
+        new_name: new_name.to_string(),
+    };
+
+    let result = github_client
+        .repos()
+        .rename_branch(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            name,
+            &request,
+        )
+        .await;
+
+    match result {
+        Ok(b) => Ok(b.body.name),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Compare `base...head`, first 100 commits are returned
+pub async fn compare(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    basehead: &str,
+) -> Result<CommitComparison, Box<dyn Error>> {
+    let result = github_client
+        .repos()
+        .compare_commits(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            1,
+            100,
+            basehead,
+        )
+        .await;
+
+    match result {
+        Ok(c) => Ok(c.body),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Branches whose last commit was merged by pull request, default and protected branches are kept
+pub fn merged<'a>(default_branch: &str, branches: &'a [BranchInfo]) -> Vec<(&'a str, i64)> {
+    branches
+        .iter()
+        .filter(|b| b.name != default_branch && !b.protected)
+        .filter_map(|b| b.merged_pull.map(|number| (b.name.as_str(), number)))
+        .collect()
+}
+
+fn branch_info(node: &Value) -> BranchInfo {
+    let sha = node["target"]["oid"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+    let merged_pull = node["associatedPullRequests"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|p| p["headRefOid"].as_str() == Some(sha.as_str()))
+        .and_then(|p| p["number"].as_i64());
+
+    BranchInfo {
+        name: node["name"].as_str().unwrap_or_default().to_string(),
+        message: node["target"]["messageHeadline"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        date: node["target"]["committedDate"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        ahead: node["compare"]["behindBy"].as_i64().unwrap_or_default(),
+        behind: node["compare"]["aheadBy"].as_i64().unwrap_or_default(),
+        protected: !node["branchProtectionRule"].is_null(),
+        merged_pull,
+        sha,
+    }
+}
+
+#[cfg(test)]
+mod branches_tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn node(name: &str, protected: bool, pulls: Value) -> Value {
+        json!({
+            "name": name,
+            "target": { "oid": "abc", "messageHeadline": "Fix", "committedDate": "2024-01-01T00:00:00Z" },
+            "branchProtectionRule": if protected { json!({ "pattern": name }) } else { Value::Null },
+            "compare": { "aheadBy": 2, "behindBy": 5 },
+            "associatedPullRequests": { "nodes": pulls },
+        })
+    }
+
+    #[rstest]
+    #[case(json!([{ "number": 7, "headRefOid": "abc" }]), Some(7))]
+    // Branch got new commits after merge
+    #[case(json!([{ "number": 7, "headRefOid": "old" }]), None)]
+    #[case(json!([]), None)]
+    fn branch_info_test(#[case] pulls: Value, #[case] merged_pull: Option<i64>) {
+        let branch = branch_info(&node("feature", false, pulls));
+
+        assert_eq!(branch.name, "feature");
+        assert_eq!(branch.sha, "abc");
+        // Compare is made from default branch, so numbers are swapped
+        assert_eq!((branch.ahead, branch.behind), (5, 2));
+        assert_eq!(branch.merged_pull, merged_pull);
+    }
+
+    #[test]
+    fn merged_skips_default_and_protected() {
+        let merged_pull = json!([{ "number": 1, "headRefOid": "abc" }]);
+        let branches = vec![
+            branch_info(&node("main", false, merged_pull.clone())),
+            branch_info(&node("release", true, merged_pull.clone())),
+            branch_info(&node("feature", false, merged_pull)),
+            branch_info(&node("wip", false, json!([]))),
+        ];
+
+        assert_eq!(merged("main", &branches), vec![("feature", 1)]);
+    }
+}
@@ -0,0 +1,327 @@
+use std::collections::HashSet;
+use std::error::Error;
+
+use octorust::Client;
+use serde_json::json;
+
+use crate::git_utils::repo_info::RepoInfo;
+use crate::git_utils::rest;
+
+// Sections in order they are written to changelog
+const SECTIONS: [&str; 10] = [
+    "Breaking changes",
+    "Features",
+    "Bug fixes",
+    "Performance",
+    "Refactoring",
+    "Documentation",
+    "Tests",
+    "Build and CI",
+    "Chores",
+    "Other",
+];
+
+// Pull requests are asked in graphql by aliases, this many in one query
+const PULLS_IN_QUERY: usize = 50;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Conventional {
+    pub kind: String,
+    pub scope: Option<String>,
+    pub breaking: bool,
+    pub description: String,
+}
+
+struct PullInfo {
+    number: i64,
+    title: String,
+    author: String,
+    labels: Vec<String>,
+    commits: Vec<String>,
+}
+
+// Markdown changelog of merged pull requests and commits between two refs (tags, branches or commits)
+pub async fn build(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    from: &str,
+    to: &str,
+    title: &str,
+) -> Result<String, Box<dyn Error>> {
+    let commits = get_commits(github_client, repo_info, from, to).await?;
+
+    let mut numbers: Vec<i64> = commits.iter().filter_map(|(_, m)| pr_number(m)).collect();
+    numbers.sort_unstable();
+    numbers.dedup();
+    let pulls = get_pulls(repo_info, &numbers).await?;
+
+    // Commits of pull requests are shown as pull request
+    let pull_commits: HashSet<&str> = pulls
+        .iter()
+        .flat_map(|p| p.commits.iter().map(String::as_str))
+        .collect();
+
+    let mut entries: Vec<(&'static str, String)> = Vec::new();
+    for pull in &pulls {
+        let conventional = parse_conventional(&pull.title);
+        let section = match section_of_labels(&pull.labels) {
+            Some(s) => s,
+            None => section_of(&conventional),
+        };
+        let text = match &conventional {
+            Some(c) => describe(c),
+            None => pull.title.clone(),
+        };
+        entries.push((
+            section,
+            format!("{} (#{}) @{}", text, pull.number, pull.author),
+        ));
+    }
+    for (sha, message) in &commits {
+        if pull_commits.contains(sha.as_str()) || pr_number(message).is_some() {
+            continue;
+        }
+        let subject = message.lines().next().unwrap_or_default();
+        let conventional = parse_conventional(subject);
+        let text = match &conventional {
+            Some(c) => describe(c),
+            None => subject.to_string(),
+        };
+        entries.push((
+            section_of(&conventional),
+            format!("{} ({})", text, &sha[..7.min(sha.len())]),
+        ));
+    }
+
+    Ok(render(title, &entries))
+}
+
+pub fn render(title: &str, entries: &[(&'static str, String)]) -> String {
+    let mut changelog = format!("## {title}\n");
+
+    for section in SECTIONS {
+        let lines: Vec<&String> = entries
+            .iter()
+            .filter(|(s, _)| *s == section)
+            .map(|(_, line)| line)
+            .collect();
+        if lines.is_empty() {
+            continue;
+        }
+
+        changelog.push_str(&format!("\n### {section}\n\n"));
+        for line in lines {
+            changelog.push_str(&format!("- {line}\n"));
+        }
+    }
+    changelog
+}
+
+// Parse subject like `feat(cli)!: add search`
+pub fn parse_conventional(subject: &str) -> Option<Conventional> {
+    let (head, description) = subject.split_once(": ")?;
+    let (head, breaking) = match head.strip_suffix('!') {
+        Some(h) => (h, true),
+        None => (head, false),
+    };
+    let (kind, scope) = match head.split_once('(') {
+        Some((kind, scope)) => (kind, Some(scope.strip_suffix(')')?.to_string())),
+        None => (head, None),
+    };
+
+    if kind.is_empty() || !kind.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    Some(Conventional {
+        kind: kind.to_lowercase(),
+        scope,
+        breaking,
+        description: description.trim().to_string(),
+    })
+}
+
+// Number of pull request from merge commit (`Merge pull request #1 from ...`) or squash commit (`title (#1)`)
+pub fn pr_number(message: &str) -> Option<i64> {
+    let subject = message.lines().next()?;
+
+    if let Some(rest) = subject.strip_prefix("Merge pull request #") {
+        return rest.split_whitespace().next()?.parse().ok();
+    }
+    subject.strip_suffix(')')?.rsplit_once("(#")?.1.parse().ok()
+}
+
+fn describe(conventional: &Conventional) -> String {
+    match &conventional.scope {
+        Some(scope) => format!("**{}:** {}", scope, conventional.description),
+        None => conventional.description.clone(),
+    }
+}
+
+fn section_of(conventional: &Option<Conventional>) -> &'static str {
+    let conventional = match conventional {
+        Some(c) => c,
+        None => return "Other",
+    };
+    if conventional.breaking {
+        return "Breaking changes";
+    }
+
+    match conventional.kind.as_str() {
+        "feat" => "Features",
+        "fix" => "Bug fixes",
+        "perf" => "Performance",
+        "refactor" => "Refactoring",
+        "docs" => "Documentation",
+        "test" => "Tests",
+        "build" | "ci" => "Build and CI",
+        "chore" | "style" => "Chores",
+        _ => "Other",
+    }
+}
+
+fn section_of_labels(labels: &[String]) -> Option<&'static str> {
+    labels
+        .iter()
+        .find_map(|label| match label.to_lowercase().as_str() {
+            "breaking" | "breaking change" => Some("Breaking changes"),
+            "feature" | "enhancement" => Some("Features"),
+            "bug" | "fix" => Some("Bug fixes"),
+            "performance" => Some("Performance"),
+            "refactoring" => Some("Refactoring"),
+            "documentation" | "docs" => Some("Documentation"),
+            "tests" => Some("Tests"),
+            "ci" => Some("Build and CI"),
+            "chore" | "dependencies" => Some("Chores"),
+            _ => None,
+        })
+}
+
+// Sha and message of commits in `to` which are not in `from`
+async fn get_commits(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    from: &str,
+    to: &str,
+) -> Result<Vec<(String, String)>, Box<dyn Error>> {
+    let basehead = format!("{from}...{to}");
+    let per_page = 100;
+    let mut commits = Vec::new();
+    let mut page = 1;
+
+    loop {
+        let comparison = github_client
+            .repos()
+            .compare_commits(
+                &repo_info.get_owner(),
+                &repo_info.get_name(),
+                page,
+                per_page,
+                &basehead,
+            )
+            .await?
+            .body;
+
+        let received = comparison.commits.len() as i64;
+        commits.extend(
+            comparison
+                .commits
+                .into_iter()
+                .map(|c| (c.sha, c.commit.message)),
+        );
+
+        if received < per_page {
+            break;
+        }
+        page += 1;
+    }
+    Ok(commits)
+}
+
+async fn get_pulls(repo_info: &RepoInfo, numbers: &[i64]) -> Result<Vec<PullInfo>, Box<dyn Error>> {
+    let mut pulls = Vec::new();
+
+    for chunk in numbers.chunks(PULLS_IN_QUERY) {
+        let fields: Vec<String> = chunk
+            .iter()
+            .map(|n| {
+                format!(
+                    "pr{n}: issueOrPullRequest(number: {n}) {{ ... on PullRequest {{ \
+                     title merged author {{ login }} labels(first: 20) {{ nodes {{ name }} }} \
+                     commits(first: 100) {{ nodes {{ commit {{ oid }} }} }} }} }}"
+                )
+            })
+            .collect();
+        let query = format!(
+            "query($owner: String!, $name: String!) {{ repository(owner: $owner, name: $name) {{ {} }} }}",
+            fields.join(" ")
+        );
+        let variables = json!({
+            "owner": repo_info.get_owner(),
+            "name": repo_info.get_name(),
+        });
+
+        let data = rest::graphql(&query, variables).await?;
+        for number in chunk {
+            let pull = &data["repository"][format!("pr{number}")];
+            // Number in commit message can point to issue or not merged pull request
+            if !pull["merged"].as_bool().unwrap_or_default() {
+                continue;
+            }
+
+            pulls.push(PullInfo {
+                number: *number,
+                title: pull["title"].as_str().unwrap_or_default().to_string(),
+                author: pull["author"]["login"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+                labels: pull["labels"]["nodes"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|l| l["name"].as_str().map(String::from))
+                    .collect(),
+                commits: pull["commits"]["nodes"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|c| c["commit"]["oid"].as_str().map(String::from))
+                    .collect(),
+            });
+        }
+    }
+    Ok(pulls)
+}
+
+#[cfg(test)]
+mod changelog_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("feat: add search", Some(("feat", None, false, "add search")))]
+    #[case("fix(cli)!: drop flag", Some(("fix", Some("cli"), true, "drop flag")))]
+    #[case("Update readme", None)]
+    #[case("Release v1.0: notes", None)]
+    fn parse_conventional_test(
+        #[case] subject: &str,
+        #[case] expected: Option<(&str, Option<&str>, bool, &str)>,
+    ) {
+        let expected = expected.map(|(kind, scope, breaking, description)| Conventional {
+            kind: kind.to_string(),
+            scope: scope.map(String::from),
+            breaking,
+            description: description.to_string(),
+        });
+        assert_eq!(parse_conventional(subject), expected);
+    }
+
+    #[rstest]
+    #[case("Merge pull request #12 from user/branch\n\nTitle", Some(12))]
+    #[case("Add search (#34)", Some(34))]
+    #[case("Fix bug in (#x)", None)]
+    #[case("Plain commit", None)]
+    fn pr_number_test(#[case] message: &str, #[case] expected: Option<i64>) {
+        assert_eq!(pr_number(message), expected);
+    }
+}
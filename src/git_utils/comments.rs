@@ -50,6 +50,28 @@ pub async fn get_all(
     }
 }
 
+// Get all Comments for issues/pull requests in repo without review comments
+pub async fn get_all_from_repo(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+) -> Result<Vec<IssueComment>, Box<dyn Error>> {
+    let list_comments = github_client
+        .issues()
+        .list_all_comments_for_repo(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            Sort::Created,
+            Order::Desc,
+            None,
+        )
+        .await;
+
+    match list_comments {
+        Ok(c) => Ok(c.body),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
 // Get all review Comments for pull request
 pub async fn get_all_from_review(
     github_client: &Client,
@@ -1,14 +1,20 @@
 use std::error::Error;
 
 use octorust::types::{
-    self, Issue, IssuesCreateRequest, IssuesCreateRequestLabelsOneOf, IssuesUpdateRequest, State,
-    TitleOneOf,
+    self, Issue, IssuesAddAssigneesRequest, IssuesAddLabelsRequest, IssuesAddLabelsRequestOneOf,
+    IssuesCreateRequest, IssuesCreateRequestLabelsOneOf, IssuesLockRequest, IssuesUpdateRequest,
+    LockReason, State, TitleOneOf,
 };
 use octorust::Client;
+use reqwest::Method;
+use serde_json::{json, Value};
 
+use crate::cli_in::set_vars::{CloseReason, IssuesListSorts, MilestoneRef};
 use crate::cli_parse::entities::{ListIssueArgs, UpdateIssueArgs};
 use crate::git_utils::comments;
+use crate::git_utils::milestones;
 use crate::git_utils::repo_info::RepoInfo;
+use crate::git_utils::rest;
 
 pub async fn get(
     github_client: &Client,
@@ -28,14 +34,39 @@ pub async fn get_list(
     repo_info: &RepoInfo,
     command_args: &ListIssueArgs,
 ) -> Result<Vec<types::IssueSimple>, Box<dyn Error>> {
-    let sort = types::IssuesListSort::Created;
+    let issues = get_list_with_reactions(github_client, repo_info, command_args).await?;
+
+    Ok(issues.into_iter().map(|(issue, _)| issue).collect())
+}
+
+// Same as `get_list`, reactions of issue are given only when list is sorted by reactions
+pub async fn get_list_with_reactions(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    command_args: &ListIssueArgs,
+) -> Result<Vec<(types::IssueSimple, Option<types::ReactionRollup>)>, Box<dyn Error>> {
+    let sort = match command_args.sort {
+        IssuesListSorts::Created => types::IssuesListSort::Created,
+        IssuesListSorts::Updated => types::IssuesListSort::Updated,
+        IssuesListSorts::Comments => types::IssuesListSort::Comments,
+        IssuesListSorts::Reactions => {
+            return get_list_by_reactions(github_client, repo_info, command_args).await
+        }
+    };
+
+    let milestone = match &command_args.milestone {
+        Some(m) => milestones::resolve(github_client, repo_info, m)
+            .await?
+            .to_string(),
+        None => String::new(),
+    };
 
     let issues = github_client
         .issues()
         .list_for_repo(
             &repo_info.get_owner(),
             &repo_info.get_name(),
-            "",
+            &milestone,
             command_args.state.0.to_owned(),
             &command_args.assignee,
             &command_args.creator,
@@ -50,16 +81,74 @@ pub async fn get_list(
         .await;
 
     match issues {
-        Ok(info) => Ok(info.body),
+        Ok(info) => Ok(info.body.into_iter().map(|issue| (issue, None)).collect()),
         Err(er) => Err(Box::new(er)),
     }
 }
 
+// Issues list endpoint can't sort by reactions, so search api is used with the same filters
+async fn get_list_by_reactions(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    command_args: &ListIssueArgs,
+) -> Result<Vec<(types::IssueSimple, Option<types::ReactionRollup>)>, Box<dyn Error>> {
+    let mut query = vec![
+        format!("repo:{}/{}", repo_info.get_owner(), repo_info.get_name()),
+        "is:issue".to_string(),
+    ];
+
+    match command_args.state.0 {
+        types::IssuesListState::Open => query.push("state:open".to_string()),
+        types::IssuesListState::Closed => query.push("state:closed".to_string()),
+        _ => {}
+    }
+    match command_args.assignee.as_str() {
+        "" => {}
+        "none" => query.push("no:assignee".to_string()),
+        "*" => query.push("-no:assignee".to_string()),
+        assignee => query.push(format!("assignee:{assignee}")),
+    }
+    if !command_args.creator.is_empty() {
+        query.push(format!("author:{}", command_args.creator));
+    }
+    for label in command_args.labels.split(',').filter(|l| !l.is_empty()) {
+        query.push(format!("label:\"{label}\""));
+    }
+    if let Some(m) = &command_args.milestone {
+        let title = milestones::get_title(github_client, repo_info, m).await?;
+        query.push(format!("milestone:\"{title}\""));
+    }
+
+    let response = rest::send_with_query(
+        Method::GET,
+        "/search/issues",
+        &[
+            ("q", &query.join(" ")),
+            ("sort", "reactions-+1"),
+            ("order", "desc"),
+            ("per_page", &command_args.iss_on_page.to_string()),
+            ("page", &command_args.numb_of_page.to_string()),
+        ],
+        None,
+    )
+    .await?;
+
+    let items = response["items"].as_array().cloned().unwrap_or_default();
+    let mut issues = Vec::new();
+    for item in items {
+        // IssueSimple has no reactions field, so they are taken from item separately
+        let reactions = serde_json::from_value(item["reactions"].clone()).ok();
+        issues.push((serde_json::from_value(item)?, reactions));
+    }
+    Ok(issues)
+}
+
 fn get_create_request(
     title: &str,
     body: &str,
     assignees: &[String],
     labels: &[String],
+    milestone: Option<i64>,
 ) -> IssuesCreateRequest {
     let new_title = TitleOneOf::String(title.to_owned());
 
@@ -78,7 +167,7 @@ fn get_create_request(
             assignees.to_owned()
         },
         labels: new_labels,
-        milestone: None,
+        milestone: milestone.map(TitleOneOf::I64),
     }
 }
 
@@ -89,8 +178,14 @@ pub async fn create(
     body: &str,
     assignees: &[String],
     labels: &[String],
+    milestone: Option<&MilestoneRef>,
 ) -> Result<String, Box<dyn Error>> {
-    let request = get_create_request(title, body, assignees, labels);
+    let milestone = match milestone {
+        Some(m) => Some(milestones::resolve(github_client, &repo_info, m).await?),
+        None => None,
+    };
+
+    let request = get_create_request(title, body, assignees, labels, milestone);
 
     let new_issue = github_client
         .issues()
@@ -108,7 +203,8 @@ fn get_update_request(
     body: Option<String>,
     assignees: Option<&[String]>,
     labels: Option<&[String]>,
-    state: &State,
+    milestone: Option<i64>,
+    state: Option<&State>,
 ) -> IssuesUpdateRequest {
     let new_title = title.map(|t| TitleOneOf::String(t.to_string()));
 
@@ -126,26 +222,61 @@ fn get_update_request(
         assignee: String::new(),
         assignees: assignees.map(|a| a.to_vec()).unwrap_or_default(),
         labels: new_labels,
-        milestone: None,
-        state: Some(state.clone()),
+        milestone: milestone.map(TitleOneOf::I64),
+        state: state.cloned(),
     }
 }
 
+// Comment goes first, so issue is not closed if comment failed
 pub async fn close(
     github_client: &Client,
-    repo_info: RepoInfo,
+    repo_info: &RepoInfo,
+    issue_number: &i64,
+    comment: &str,
+    reason: &CloseReason,
+) -> Result<String, Box<dyn Error>> {
+    if !comment.is_empty() {
+        comments::create(github_client, repo_info, issue_number, comment)
+            .await
+            .map_err(|er| format!("comment not created, issue not closed: {er}"))?;
+    }
+
+    let request = json!({
+        "state": "closed",
+        "state_reason": reason.to_string(),
+    });
+
+    let path = format!(
+        "/repos/{}/{}/issues/{}",
+        repo_info.get_owner(),
+        repo_info.get_name(),
+        issue_number
+    );
+    match rest::send(Method::PATCH, &path, Some(&request)).await {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) if !comment.is_empty() => {
+            Err(format!("comment created, but issue not closed: {er}").into())
+        }
+        Err(er) => Err(er),
+    }
+}
+
+// Comment goes first, so issue is not reopened if comment failed
+pub async fn reopen(
+    github_client: &Client,
+    repo_info: &RepoInfo,
     issue_number: &i64,
     comment: &str,
 ) -> Result<String, Box<dyn Error>> {
-    if comment.is_empty() {
-        let new_comment =
-            comments::create(github_client, &repo_info, issue_number, comment).await?;
-        println!("{new_comment}");
+    if !comment.is_empty() {
+        comments::create(github_client, repo_info, issue_number, comment)
+            .await
+            .map_err(|er| format!("comment not created, issue not reopened: {er}"))?;
     }
 
-    let request = get_update_request(None, None, None, None, &State::Closed);
+    let request = get_update_request(None, None, None, None, None, Some(&State::Open));
 
-    let close = github_client
+    let reopen = github_client
         .issues()
         .update(
             &repo_info.get_owner(),
@@ -155,39 +286,364 @@ pub async fn close(
         )
         .await;
 
-    match close {
+    match reopen {
         Ok(_) => Ok("Success".to_string()),
+        Err(er) if !comment.is_empty() => {
+            Err(format!("comment created, but issue not reopened: {er}").into())
+        }
         Err(er) => Err(Box::new(er)),
     }
 }
 
+// Change only given fields. Empty labels/assignees lists remove all of them
 pub async fn update(
     github_client: &Client,
-    repo_info: RepoInfo,
+    repo_info: &RepoInfo,
+    issue_number: i64,
     command_args: UpdateIssueArgs,
-    assignees: &[String],
-    labels: &[String],
 ) -> Result<String, Box<dyn Error>> {
-    let request = get_update_request(
-        command_args.title,
-        command_args.body,
-        Some(assignees),
-        Some(labels),
-        &command_args.state.0,
-    );
+    let milestone = match &command_args.milestone {
+        Some(m) => Some(milestones::resolve(github_client, repo_info, m).await?),
+        None => None,
+    };
 
-    let update_iss = github_client
+    let has_fields = command_args.title.is_some()
+        || command_args.body.is_some()
+        || command_args.state.is_some()
+        || milestone.is_some()
+        || command_args.labels.as_ref().is_some_and(|l| !l.is_empty())
+        || command_args
+            .assignees
+            .as_ref()
+            .is_some_and(|a| !a.is_empty());
+
+    if has_fields {
+        let request = get_update_request(
+            command_args.title,
+            command_args.body,
+            command_args.assignees.as_deref(),
+            command_args.labels.as_deref(),
+            milestone,
+            command_args.state.as_ref().map(|s| &s.0),
+        );
+
+        github_client
+            .issues()
+            .update(
+                &repo_info.get_owner(),
+                &repo_info.get_name(),
+                issue_number,
+                &request,
+            )
+            .await?;
+    }
+
+    // Github skips empty lists in update request, so they are cleared by dedicated endpoints
+    if command_args.labels.as_ref().is_some_and(|l| l.is_empty()) {
+        remove_all_labels(github_client, repo_info, issue_number).await?;
+    }
+    if command_args
+        .assignees
+        .as_ref()
+        .is_some_and(|a| a.is_empty())
+    {
+        let issue = get(github_client, repo_info, issue_number).await?;
+        let current: Vec<String> = issue.assignees.into_iter().map(|a| a.login).collect();
+        if !current.is_empty() {
+            remove_assignees(github_client, repo_info, issue_number, &current).await?;
+        }
+    }
+
+    if !command_args.add_labels.is_empty() {
+        add_labels(
+            github_client,
+            repo_info,
+            issue_number,
+            &command_args.add_labels,
+        )
+        .await?;
+    }
+    for label in &command_args.remove_labels {
+        remove_label(github_client, repo_info, issue_number, label).await?;
+    }
+    if !command_args.add_assignees.is_empty() {
+        add_assignees(
+            github_client,
+            repo_info,
+            issue_number,
+            &command_args.add_assignees,
+        )
+        .await?;
+    }
+    if !command_args.remove_assignees.is_empty() {
+        remove_assignees(
+            github_client,
+            repo_info,
+            issue_number,
+            &command_args.remove_assignees,
+        )
+        .await?;
+    }
+
+    Ok("Success".to_string())
+}
+
+// Set state of issue without touching other fields
+pub async fn set_state(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    issue_number: i64,
+    state: State,
+) -> Result<String, Box<dyn Error>> {
+    let request = get_update_request(None, None, None, None, None, Some(&state));
+
+    let result = github_client
         .issues()
         .update(
             &repo_info.get_owner(),
             &repo_info.get_name(),
-            command_args.number,
+            issue_number,
             &request,
         )
         .await;
 
-    match update_iss {
+    match result {
         Ok(_) => Ok("Success".to_string()),
         Err(er) => Err(Box::new(er)),
     }
 }
+
+// Add labels to issue, keeping labels that it already has
+pub async fn add_labels(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    issue_number: i64,
+    labels: &[String],
+) -> Result<String, Box<dyn Error>> {
+    let request = IssuesAddLabelsRequestOneOf::IssuesAddLabelsRequest(IssuesAddLabelsRequest {
+        labels: labels.to_vec(),
+    });
+
+    let result = github_client
+        .issues()
+        .add_labels(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            issue_number,
+            &request,
+        )
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Add assignees to issue, keeping assignees that it already has
+pub async fn add_assignees(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    issue_number: i64,
+    assignees: &[String],
+) -> Result<String, Box<dyn Error>> {
+    let request = IssuesAddAssigneesRequest {
+        assignees: assignees.to_vec(),
+    };
+
+    let result = github_client
+        .issues()
+        .add_assignees(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            issue_number,
+            &request,
+        )
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+pub async fn remove_label(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    issue_number: i64,
+    label: &str,
+) -> Result<String, Box<dyn Error>> {
+    let result = github_client
+        .issues()
+        .remove_label(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            issue_number,
+            label,
+        )
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+pub async fn remove_all_labels(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    issue_number: i64,
+) -> Result<String, Box<dyn Error>> {
+    let result = github_client
+        .issues()
+        .remove_all_labels(&repo_info.get_owner(), &repo_info.get_name(), issue_number)
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+pub async fn remove_assignees(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    issue_number: i64,
+    assignees: &[String],
+) -> Result<String, Box<dyn Error>> {
+    let request = IssuesAddAssigneesRequest {
+        assignees: assignees.to_vec(),
+    };
+
+    let result = github_client
+        .issues()
+        .remove_assignees(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            issue_number,
+            &request,
+        )
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+pub async fn lock(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    issue_number: i64,
+    reason: Option<LockReason>,
+) -> Result<String, Box<dyn Error>> {
+    let request = IssuesLockRequest {
+        lock_reason: reason,
+    };
+
+    let result = github_client
+        .issues()
+        .lock(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            issue_number,
+            &request,
+        )
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+pub async fn unlock(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    issue_number: i64,
+) -> Result<String, Box<dyn Error>> {
+    let result = github_client
+        .issues()
+        .unlock(&repo_info.get_owner(), &repo_info.get_name(), issue_number)
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Pin or unpin issue in repo. Rest api can't do it, so graphql is used
+pub async fn set_pinned(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    issue_number: i64,
+    pinned: bool,
+) -> Result<String, Box<dyn Error>> {
+    let issue = get(github_client, repo_info, issue_number).await?;
+
+    let query = if pinned {
+        "mutation($id: ID!) { pinIssue(input: {issueId: $id}) { issue { number } } }"
+    } else {
+        "mutation($id: ID!) { unpinIssue(input: {issueId: $id}) { issue { number } } }"
+    };
+    rest::graphql(query, json!({ "id": issue.node_id })).await?;
+
+    Ok("Success".to_string())
+}
+
+// Move issue to other repo of the same owner, returns url of moved issue
+pub async fn transfer(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    issue_number: i64,
+    target: &RepoInfo,
+) -> Result<String, Box<dyn Error>> {
+    let issue = get(github_client, repo_info, issue_number).await?;
+    let target_repo = github_client
+        .repos()
+        .get(&target.get_owner(), &target.get_name())
+        .await?;
+
+    let query = "mutation($issue: ID!, $repo: ID!) { \
+        transferIssue(input: {issueId: $issue, repositoryId: $repo}) { issue { url } } }";
+    let data = rest::graphql(
+        query,
+        json!({ "issue": issue.node_id, "repo": target_repo.body.node_id }),
+    )
+    .await?;
+
+    match data["transferIssue"]["issue"]["url"].as_str() {
+        Some(url) => Ok(url.to_string()),
+        None => Ok("Success".to_string()),
+    }
+}
+
+// Get all events of issue timeline. Octorust can't parse them, so raw json is returned
+pub async fn get_timeline(
+    repo_info: &RepoInfo,
+    issue_number: i64,
+) -> Result<Vec<Value>, Box<dyn Error>> {
+    let mut events = Vec::new();
+    let mut page = 1;
+
+    loop {
+        let path = format!(
+            "/repos/{}/{}/issues/{}/timeline?per_page=100&page={}",
+            repo_info.get_owner(),
+            repo_info.get_name(),
+            issue_number,
+            page
+        );
+        let response = rest::send(Method::GET, &path, None).await?;
+
+        let page_events = response.as_array().cloned().unwrap_or_default();
+        let last_page = page_events.len() < 100;
+        events.extend(page_events);
+
+        if last_page {
+            return Ok(events);
+        }
+        page += 1;
+    }
+}
@@ -0,0 +1,284 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use octorust::types::{IssuesCreateLabelRequest, IssuesUpdateLabelRequest, Label};
+use octorust::Client;
+use reqwest::{Method, Url};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::cli_in::set_vars::LabelColor;
+use crate::git_utils::repo_info::RepoInfo;
+use crate::git_utils::rest;
+
+/// Label as it must be in repo
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct LabelSpec {
+    pub name: String,
+    pub color: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+impl From<Label> for LabelSpec {
+    fn from(label: Label) -> Self {
+        LabelSpec {
+            name: label.name,
+            color: label.color,
+            description: label.description,
+        }
+    }
+}
+
+/// One change needed to bring repo labels to wanted state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LabelChange {
+    Create(LabelSpec),
+    Update { old: LabelSpec, new: LabelSpec },
+    Delete(LabelSpec),
+}
+
+pub async fn get_all(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+) -> Result<Vec<Label>, Box<dyn Error>> {
+    let labels = github_client
+        .issues()
+        .list_all_labels_for_repo(&repo_info.get_owner(), &repo_info.get_name())
+        .await;
+
+    match labels {
+        Ok(l) => Ok(l.body),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+pub async fn create(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    label: &LabelSpec,
+) -> Result<String, Box<dyn Error>> {
+    let request = IssuesCreateLabelRequest {
+        name: label.name.to_owned(),
+        color: label.color.to_owned(),
+        description: label.description.to_owned(),
+    };
+
+    let result = github_client
+        .issues()
+        .create_label(&repo_info.get_owner(), &repo_info.get_name(), &request)
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Empty fields are not changed
+pub async fn update(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    name: &str,
+    new_name: &str,
+    color: &str,
+    description: &str,
+) -> Result<String, Box<dyn Error>> {
+    let request = IssuesUpdateLabelRequest {
+        new_name: new_name.to_owned(),
+        color: color.to_owned(),
+        description: description.to_owned(),
+    };
+
+    let result = github_client
+        .issues()
+        .update_label(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            name,
+            &request,
+        )
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Set all fields of label. Octorust update skips empty description, so raw request is used
+// to clear it
+pub async fn replace(
+    repo_info: &RepoInfo,
+    name: &str,
+    label: &LabelSpec,
+) -> Result<String, Box<dyn Error>> {
+    let request = json!({
+        "new_name": label.name,
+        "color": label.color,
+        "description": label.description,
+    });
+
+    rest::send(Method::PATCH, &label_path(repo_info, name)?, Some(&request)).await?;
+    Ok("Success".to_string())
+}
+
+pub async fn delete(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    name: &str,
+) -> Result<String, Box<dyn Error>> {
+    let result = github_client
+        .issues()
+        .delete_label(&repo_info.get_owner(), &repo_info.get_name(), name)
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Read yaml file with list of labels and check their colors
+pub fn read_file(path: &Path) -> Result<Vec<LabelSpec>, Box<dyn Error>> {
+    let content = fs::read_to_string(path)?;
+    let labels: Vec<LabelSpec> = serde_yaml::from_str(&content)?;
+
+    labels
+        .into_iter()
+        .map(|label| {
+            let color = LabelColor::from_str(&label.color)
+                .map_err(|er| format!("Label '{}': {er}", label.name))?;
+            Ok(LabelSpec {
+                color: color.0,
+                ..label
+            })
+        })
+        .collect()
+}
+
+// Label names in github are case insensitive
+pub fn plan(current: &[LabelSpec], wanted: &[LabelSpec], prune: bool) -> Vec<LabelChange> {
+    let mut changes = Vec::new();
+
+    for new in wanted {
+        match current
+            .iter()
+            .find(|old| old.name.eq_ignore_ascii_case(&new.name))
+        {
+            None => changes.push(LabelChange::Create(new.clone())),
+            Some(old) => {
+                if old.name != new.name
+                    || !old.color.eq_ignore_ascii_case(&new.color)
+                    || old.description != new.description
+                {
+                    changes.push(LabelChange::Update {
+                        old: old.clone(),
+                        new: new.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    if prune {
+        for old in current {
+            if !wanted
+                .iter()
+                .any(|new| new.name.eq_ignore_ascii_case(&old.name))
+            {
+                changes.push(LabelChange::Delete(old.clone()));
+            }
+        }
+    }
+    changes
+}
+
+pub async fn apply(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    changes: &[LabelChange],
+) -> Result<(), Box<dyn Error>> {
+    for change in changes {
+        match change {
+            LabelChange::Create(new) => {
+                create(github_client, repo_info, new).await?;
+            }
+            LabelChange::Update { old, new } => {
+                replace(repo_info, &old.name, new).await?;
+            }
+            LabelChange::Delete(old) => {
+                delete(github_client, repo_info, &old.name).await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// Label name goes to url path, so it's encoded as path segment (names can have spaces, `/`)
+fn label_path(repo_info: &RepoInfo, name: &str) -> Result<String, Box<dyn Error>> {
+    let mut url = Url::parse("https://api.github.com")?;
+    url.path_segments_mut().map_err(|_| "Bad api url")?.extend([
+        "repos",
+        &repo_info.get_owner(),
+        &repo_info.get_name(),
+        "labels",
+        name,
+    ]);
+
+    Ok(url.path().to_string())
+}
+
+#[cfg(test)]
+mod labels_tests {
+    use super::*;
+
+    fn label(name: &str, color: &str, description: &str) -> LabelSpec {
+        LabelSpec {
+            name: name.to_string(),
+            color: color.to_string(),
+            description: description.to_string(),
+        }
+    }
+
+    #[test]
+    fn plan_changes() {
+        let current = vec![
+            label("bug", "d73a4a", "Something isn't working"),
+            label("Docs", "0075ca", ""),
+            label("wontfix", "ffffff", ""),
+        ];
+        let wanted = vec![
+            label("bug", "D73A4A", "Something isn't working"),
+            label("docs", "0075ca", ""),
+            label("ui", "a2eeef", "Interface"),
+        ];
+
+        assert_eq!(
+            plan(&current, &wanted, false),
+            vec![
+                LabelChange::Update {
+                    old: current[1].clone(),
+                    new: wanted[1].clone(),
+                },
+                LabelChange::Create(wanted[2].clone()),
+            ]
+        );
+        assert_eq!(
+            plan(&current, &wanted, true).last(),
+            Some(&LabelChange::Delete(current[2].clone()))
+        );
+    }
+
+    #[test]
+    fn label_path_encoded() {
+        let repo_info = "owner/repo".parse::<RepoInfo>().unwrap();
+
+        assert_eq!(
+            label_path(&repo_info, "good first issue/ui").unwrap(),
+            "/repos/owner/repo/labels/good%20first%20issue%2Fui"
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use std::error::Error;
+use std::ffi::OsStr;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+use std::process::Command;
+
+use serde_json::Value;
+
+use crate::cli_in::set_vars::GitProtocol;
+use crate::git_utils::repo_info::RepoInfo;
+
+// Manifests with project version, checked in this order
+const MANIFESTS: [&str; 3] = ["Cargo.toml", "package.json", "pyproject.toml"];
+
+// Full hash of local HEAD commit
+pub fn head_sha() -> Result<String, io::Error> {
+    git(&["rev-parse", "HEAD"])
+}
+
+// Work tree has not committed changes (untracked files too)
+pub fn is_dirty() -> Result<bool, io::Error> {
+    Ok(!git(&["status", "--porcelain"])?.is_empty())
+}
+
+pub fn clone_url(repo_info: &RepoInfo, protocol: &GitProtocol) -> String {
+    match protocol {
+        GitProtocol::Ssh => repo_info.get_ssh(),
+        GitProtocol::Https => repo_info.get_https(),
+    }
+}
+
+pub fn clone(url: &str, dir: &Path) -> Result<(), io::Error> {
+    git_in(
+        Path::new("."),
+        &[OsStr::new("clone"), OsStr::new(url), dir.as_os_str()],
+    )?;
+    Ok(())
+}
+
+pub fn add_remote(dir: &Path, name: &str, url: &str) -> Result<(), io::Error> {
+    git_in(dir, &["remote", "add", name, url])?;
+    Ok(())
+}
+
+// Update current branch of cloned repo, fails if it diverged from remote
+pub fn pull_ff_only(dir: &Path) -> Result<(), io::Error> {
+    git_in(dir, &["pull", "--ff-only"])?;
+    Ok(())
+}
+
+pub fn current_branch() -> Result<String, io::Error> {
+    git(&["rev-parse", "--abbrev-ref", "HEAD"])
+}
+
+// Fast-forward local branch to `origin`. Branch which is not checked out is updated without checkout
+pub fn fast_forward(branch: &str) -> Result<(), io::Error> {
+    let result = if current_branch()? == branch {
+        git(&["pull", "--ff-only", "origin", branch])
+    } else {
+        git(&["fetch", "origin", &format!("{branch}:{branch}")])
+    };
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(er) => Err(io::Error::other(format!(
+            "Local branch {branch} can't be fast-forwarded to origin, histories may have diverged. {er}"
+        ))),
+    }
+}
+
+// Project version from first found manifest in current directory
+pub fn manifest_version() -> Result<String, Box<dyn Error>> {
+    for manifest in MANIFESTS {
+        let text = match fs::read_to_string(manifest) {
+            Ok(t) => t,
+            Err(er) if er.kind() == ErrorKind::NotFound => continue,
+            Err(er) => return Err(Box::new(er)),
+        };
+
+        return match parse_manifest_version(manifest, &text)? {
+            Some(version) => Ok(version),
+            None => Err(Box::new(io::Error::new(
+                ErrorKind::InvalidData,
+                format!("Version not found in {manifest}"),
+            ))),
+        };
+    }
+
+    Err(Box::new(io::Error::new(
+        ErrorKind::NotFound,
+        format!("None of {} found", MANIFESTS.join(", ")),
+    )))
+}
+
+pub fn parse_manifest_version(
+    manifest: &str,
+    text: &str,
+) -> Result<Option<String>, Box<dyn Error>> {
+    let version = match manifest {
+        "package.json" => {
+            let package: Value = serde_json::from_str(text)?;
+            package["version"].as_str().map(String::from)
+        }
+        _ => {
+            let table = text.parse::<toml::Table>()?;
+            // Cargo.toml has `[package]`, pyproject.toml has `[project]` or `[tool.poetry]`
+            let version = [
+                table.get("package"),
+                table.get("project"),
+                table.get("tool").and_then(|t| t.get("poetry")),
+            ]
+            .into_iter()
+            .flatten()
+            .find_map(|section| section.get("version")?.as_str().map(String::from));
+            version
+        }
+    };
+    Ok(version)
+}
+
+fn git(args: &[&str]) -> Result<String, io::Error> {
+    git_in(Path::new("."), args)
+}
+
+fn git_in<S: AsRef<OsStr>>(dir: &Path, args: &[S]) -> Result<String, io::Error> {
+    let output = Command::new("git").current_dir(dir).args(args).output()?;
+
+    if !output.status.success() {
+        let command: Vec<_> = args.iter().map(|a| a.as_ref().to_string_lossy()).collect();
+        return Err(io::Error::other(format!(
+            "git {} failed: {}",
+            command.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod local_repo_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        "Cargo.toml",
+        "[package]\nname = \"app\"\nversion = \"1.2.3\"\n",
+        Some("1.2.3")
+    )]
+    #[case("Cargo.toml", "[workspace]\nmembers = [\"app\"]\n", None)]
+    #[case(
+        "package.json",
+        "{\"name\": \"app\", \"version\": \"0.4.0\"}",
+        Some("0.4.0")
+    )]
+    #[case("pyproject.toml", "[project]\nversion = \"2.0.0\"\n", Some("2.0.0"))]
+    #[case(
+        "pyproject.toml",
+        "[tool.poetry]\nversion = \"0.1.0\"\n",
+        Some("0.1.0")
+    )]
+    fn parse_manifest_version_test(
+        #[case] manifest: &str,
+        #[case] text: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(
+            parse_manifest_version(manifest, text).unwrap(),
+            expected.map(String::from)
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use std::error::Error;
+use std::io;
+
+use chrono::{DateTime, Utc};
+use octorust::types::{
+    IssuesCreateMilestoneRequest, IssuesListMilestonesSort, IssuesListState, Milestone, Order,
+    State,
+};
+use octorust::Client;
+
+use crate::cli_in::set_vars::MilestoneRef;
+use crate::git_utils::repo_info::RepoInfo;
+
+pub async fn get_all(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    state: IssuesListState,
+) -> Result<Vec<Milestone>, Box<dyn Error>> {
+    let milestones = github_client
+        .issues()
+        .list_all_milestones(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            state,
+            IssuesListMilestonesSort::DueOn,
+            Order::Asc,
+        )
+        .await;
+
+    match milestones {
+        Ok(m) => Ok(m.body),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+pub async fn create(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    title: &str,
+    description: &str,
+    due_on: Option<DateTime<Utc>>,
+) -> Result<String, Box<dyn Error>> {
+    let request = IssuesCreateMilestoneRequest {
+        title: title.to_owned(),
+        description: description.to_owned(),
+        due_on,
+        state: None,
+    };
+
+    let milestone = github_client
+        .issues()
+        .create_milestone(&repo_info.get_owner(), &repo_info.get_name(), &request)
+        .await;
+
+    match milestone {
+        Ok(m) => Ok(m.body.html_url),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Empty and None fields are not changed
+pub async fn update(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    number: i64,
+    title: &str,
+    description: &str,
+    due_on: Option<DateTime<Utc>>,
+    state: Option<State>,
+) -> Result<String, Box<dyn Error>> {
+    let request = IssuesCreateMilestoneRequest {
+        title: title.to_owned(),
+        description: description.to_owned(),
+        due_on,
+        state,
+    };
+
+    let milestone = github_client
+        .issues()
+        .update_milestone(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            number,
+            &request,
+        )
+        .await;
+
+    match milestone {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+pub async fn delete(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    number: i64,
+) -> Result<String, Box<dyn Error>> {
+    let result = github_client
+        .issues()
+        .delete_milestone(&repo_info.get_owner(), &repo_info.get_name(), number)
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Get milestone number, searching title in all milestones of repo
+pub async fn resolve(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    milestone: &MilestoneRef,
+) -> Result<i64, Box<dyn Error>> {
+    match milestone {
+        MilestoneRef::Number(number) => Ok(*number),
+        MilestoneRef::Title(title) => {
+            let milestones = get_all(github_client, repo_info, IssuesListState::All).await?;
+            match milestones.iter().find(|m| m.title == *title) {
+                Some(m) => Ok(m.number),
+                None => Err(Box::new(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("Milestone '{title}' not found"),
+                ))),
+            }
+        }
+    }
+}
+
+// Get milestone title, searching number in milestones of repo
+pub async fn get_title(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    milestone: &MilestoneRef,
+) -> Result<String, Box<dyn Error>> {
+    match milestone {
+        MilestoneRef::Title(title) => Ok(title.to_owned()),
+        MilestoneRef::Number(number) => {
+            let result = github_client
+                .issues()
+                .get_milestone(&repo_info.get_owner(), &repo_info.get_name(), *number)
+                .await;
+
+            match result {
+                Ok(m) => Ok(m.body.title),
+                Err(er) => Err(Box::new(er)),
+            }
+        }
+    }
+}
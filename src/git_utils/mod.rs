@@ -1,6 +1,23 @@
+pub mod assets;
+pub mod branches;
+pub mod changelog;
 pub mod comments;
 pub mod issues;
+pub mod labels;
+pub mod local_repo;
+pub mod milestones;
+pub mod notifications;
+pub mod protection;
+pub mod provision;
+pub mod pulls;
+pub mod reactions;
 pub mod releases;
 pub mod repo_info;
 pub mod repos;
+mod rest;
+pub mod rulesets;
+pub mod search;
+pub mod spec_diff;
+pub mod status;
 mod teams;
+pub mod versions;
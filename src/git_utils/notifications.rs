@@ -0,0 +1,170 @@
+use std::error::Error;
+use std::io;
+
+use chrono::{DateTime, Utc};
+use octorust::types::{ActivitySetThreadSubscriptionRequest, Thread};
+use octorust::Client;
+use reqwest::Method;
+use serde_json::json;
+
+use crate::git_utils::repo_info::RepoInfo;
+use crate::git_utils::rest;
+
+// Github asks to poll notifications not more often than this, if header is missing
+const DEFAULT_POLL_INTERVAL: u64 = 60;
+
+// Notifications of all repos or of one repo. `all` includes read notifications
+pub async fn get_all(
+    github_client: &Client,
+    repo_info: Option<&RepoInfo>,
+    all: bool,
+    participating: bool,
+) -> Result<Vec<Thread>, Box<dyn Error>> {
+    let activity = github_client.activity();
+
+    let threads = match repo_info {
+        Some(r) => {
+            activity
+                .list_all_repo_notifications_for_authenticated_user(
+                    &r.get_owner(),
+                    &r.get_name(),
+                    all,
+                    participating,
+                    None,
+                    None,
+                )
+                .await
+        }
+        None => {
+            activity
+                .list_all_notifications_for_authenticated_user(all, participating, None, None)
+                .await
+        }
+    };
+
+    match threads {
+        Ok(t) => Ok(t.body),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Unread notifications (of all repos or of one repo) updated after `since` and seconds to wait
+// before next request (X-Poll-Interval)
+pub async fn get_new(
+    github_client: &Client,
+    repo_info: Option<&RepoInfo>,
+    participating: bool,
+    since: DateTime<Utc>,
+) -> Result<(Vec<Thread>, u64), Box<dyn Error>> {
+    let activity = github_client.activity();
+
+    let response = match repo_info {
+        Some(r) => {
+            activity
+                .list_repo_notifications_for_authenticated_user(
+                    &r.get_owner(),
+                    &r.get_name(),
+                    false,
+                    participating,
+                    Some(since),
+                    None,
+                    50,
+                    1,
+                )
+                .await?
+        }
+        None => {
+            activity
+                .list_notifications_for_authenticated_user(
+                    false,
+                    participating,
+                    Some(since),
+                    None,
+                    50,
+                    1,
+                )
+                .await?
+        }
+    };
+
+    let poll_interval = response
+        .headers
+        .get("x-poll-interval")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_POLL_INTERVAL);
+
+    Ok((response.body, poll_interval))
+}
+
+pub async fn mark_read(github_client: &Client, thread_id: i64) -> Result<String, Box<dyn Error>> {
+    let result = github_client
+        .activity()
+        .mark_thread_as_read(thread_id)
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Github answers with empty body here, which octorust can't parse, so raw request is used
+pub async fn mark_all_read(repo_info: Option<&RepoInfo>) -> Result<String, Box<dyn Error>> {
+    let path = match repo_info {
+        Some(r) => format!("/repos/{}/{}/notifications", r.get_owner(), r.get_name()),
+        None => "/notifications".to_string(),
+    };
+
+    rest::send(Method::PUT, &path, Some(&json!({ "read": true }))).await?;
+    Ok("Success".to_string())
+}
+
+// Ignore thread: no more notifications until you are mentioned again
+pub async fn mute(github_client: &Client, thread_id: i64) -> Result<String, Box<dyn Error>> {
+    let request = ActivitySetThreadSubscriptionRequest {
+        ignored: Some(true),
+    };
+
+    let result = github_client
+        .activity()
+        .set_thread_subscription(thread_id, &request)
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+pub async fn get(github_client: &Client, thread_id: i64) -> Result<Thread, Box<dyn Error>> {
+    let thread = github_client.activity().get_thread(thread_id).await?;
+
+    Ok(thread.body)
+}
+
+// Repo and number of issue/pull request from thread subject url like `.../repos/owner/repo/issues/1`
+pub fn subject_issue(thread: &Thread) -> Result<(RepoInfo, i64), Box<dyn Error>> {
+    let not_issue = || {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "Notification {} is about {}, not issue or pull request",
+                thread.id, thread.subject.type_
+            ),
+        )
+    };
+
+    let path = match thread.subject.url.split_once("/repos/") {
+        Some((_, path)) => path,
+        None => return Err(Box::new(not_issue())),
+    };
+
+    match path.split('/').collect::<Vec<_>>().as_slice() {
+        [owner, repo, "issues" | "pulls", number] => {
+            let repo_info = format!("{owner}/{repo}").parse::<RepoInfo>()?;
+            Ok((repo_info, number.parse::<i64>()?))
+        }
+        _ => Err(Box::new(not_issue())),
+    }
+}
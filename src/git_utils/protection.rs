@@ -0,0 +1,239 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use reqwest::{Method, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::git_utils::repo_info::RepoInfo;
+use crate::git_utils::rest;
+
+/// Branch protection as it must be in repo. Not set sections are turned off
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProtectionSpec {
+    pub required_reviews: Option<ReviewsSpec>,
+    pub status_checks: Option<StatusChecksSpec>,
+    pub enforce_admins: bool,
+    pub linear_history: bool,
+    pub signed_commits: bool,
+    pub allow_force_pushes: bool,
+    pub allow_deletions: bool,
+    /// Who can push, only for org repos
+    pub restrictions: Option<RestrictionsSpec>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReviewsSpec {
+    pub count: i64,
+    pub dismiss_stale: bool,
+    pub code_owners: bool,
+}
+
+impl Default for ReviewsSpec {
+    fn default() -> Self {
+        ReviewsSpec {
+            count: 1,
+            dismiss_stale: false,
+            code_owners: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StatusChecksSpec {
+    /// Branch must be up to date with base before merge
+    pub strict: bool,
+    pub contexts: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RestrictionsSpec {
+    pub users: Vec<String>,
+    pub teams: Vec<String>,
+    pub apps: Vec<String>,
+}
+
+// Not protected branch is returned as default spec
+pub async fn get(repo_info: &RepoInfo, branch: &str) -> Result<ProtectionSpec, Box<dyn Error>> {
+    let protection = match rest::send(Method::GET, &protection_path(repo_info, branch), None).await
+    {
+        Ok(p) => p,
+        Err(er) => {
+            return match er.downcast_ref::<rest::ApiError>() {
+                Some(api_er) if api_er.status == StatusCode::NOT_FOUND => {
+                    Ok(ProtectionSpec::default())
+                }
+                _ => Err(er),
+            }
+        }
+    };
+
+    Ok(from_response(&protection))
+}
+
+// Whole protection is replaced, signed commits have own endpoint and are changed only if differ
+pub async fn set(
+    repo_info: &RepoInfo,
+    branch: &str,
+    current: &ProtectionSpec,
+    wanted: &ProtectionSpec,
+) -> Result<String, Box<dyn Error>> {
+    let path = protection_path(repo_info, branch);
+
+    rest::send(Method::PUT, &path, Some(&to_request(wanted))).await?;
+
+    if current.signed_commits != wanted.signed_commits {
+        let method = if wanted.signed_commits {
+            Method::POST
+        } else {
+            Method::DELETE
+        };
+        rest::send(method, &format!("{path}/required_signatures"), None).await?;
+    }
+
+    Ok("Success".to_string())
+}
+
+pub fn read_file(path: &Path) -> Result<ProtectionSpec, Box<dyn Error>> {
+    let content = fs::read_to_string(path)?;
+
+    Ok(serde_yaml::from_str(&content)?)
+}
+
+// Github answers with sections like `{"enabled": true}` and users/teams as objects
+pub fn from_response(protection: &Value) -> ProtectionSpec {
+    let enabled = |section: &str| protection[section]["enabled"].as_bool().unwrap_or_default();
+    let names = |list: &Value, field: &str| -> Vec<String> {
+        list.as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|item| item[field].as_str().map(String::from))
+            .collect()
+    };
+
+    let reviews = &protection["required_pull_request_reviews"];
+    let checks = &protection["required_status_checks"];
+    let restrictions = &protection["restrictions"];
+
+    ProtectionSpec {
+        required_reviews: (!reviews.is_null()).then(|| ReviewsSpec {
+            count: reviews["required_approving_review_count"]
+                .as_i64()
+                .unwrap_or_default(),
+            dismiss_stale: reviews["dismiss_stale_reviews"]
+                .as_bool()
+                .unwrap_or_default(),
+            code_owners: reviews["require_code_owner_reviews"]
+                .as_bool()
+                .unwrap_or_default(),
+        }),
+        status_checks: (!checks.is_null()).then(|| StatusChecksSpec {
+            strict: checks["strict"].as_bool().unwrap_or_default(),
+            contexts: checks["contexts"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|c| c.as_str().map(String::from))
+                .collect(),
+        }),
+        enforce_admins: enabled("enforce_admins"),
+        linear_history: enabled("required_linear_history"),
+        signed_commits: enabled("required_signatures"),
+        allow_force_pushes: enabled("allow_force_pushes"),
+        allow_deletions: enabled("allow_deletions"),
+        restrictions: (!restrictions.is_null()).then(|| RestrictionsSpec {
+            users: names(&restrictions["users"], "login"),
+            teams: names(&restrictions["teams"], "slug"),
+            apps: names(&restrictions["apps"], "slug"),
+        }),
+    }
+}
+
+fn to_request(spec: &ProtectionSpec) -> Value {
+    json!({
+        "required_status_checks": spec.status_checks.as_ref().map(|c| json!({
+            "strict": c.strict,
+            "contexts": c.contexts,
+        })),
+        "enforce_admins": spec.enforce_admins,
+        "required_pull_request_reviews": spec.required_reviews.as_ref().map(|r| json!({
+            "required_approving_review_count": r.count,
+            "dismiss_stale_reviews": r.dismiss_stale,
+            "require_code_owner_reviews": r.code_owners,
+        })),
+        "restrictions": spec.restrictions.as_ref().map(|r| json!({
+            "users": r.users,
+            "teams": r.teams,
+            "apps": r.apps,
+        })),
+        "required_linear_history": spec.linear_history,
+        "allow_force_pushes": spec.allow_force_pushes,
+        "allow_deletions": spec.allow_deletions,
+    })
+}
+
+fn protection_path(repo_info: &RepoInfo, branch: &str) -> String {
+    format!(
+        "/repos/{}/{}/branches/{}/protection",
+        repo_info.get_owner(),
+        repo_info.get_name(),
+        branch
+    )
+}
+
+#[cfg(test)]
+mod protection_tests {
+    use super::*;
+
+    #[test]
+    fn from_response_test() {
+        let response = json!({
+            "required_pull_request_reviews": {
+                "required_approving_review_count": 2,
+                "dismiss_stale_reviews": true,
+                "require_code_owner_reviews": false,
+            },
+            "required_status_checks": { "strict": true, "contexts": ["ci"] },
+            "enforce_admins": { "enabled": true },
+            "required_linear_history": { "enabled": true },
+            "allow_force_pushes": { "enabled": false },
+            "restrictions": { "users": [{ "login": "octocat" }], "teams": [], "apps": [] },
+        });
+
+        let spec = ProtectionSpec {
+            required_reviews: Some(ReviewsSpec {
+                count: 2,
+                dismiss_stale: true,
+                code_owners: false,
+            }),
+            status_checks: Some(StatusChecksSpec {
+                strict: true,
+                contexts: vec!["ci".to_string()],
+            }),
+            enforce_admins: true,
+            linear_history: true,
+            restrictions: Some(RestrictionsSpec {
+                users: vec!["octocat".to_string()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(from_response(&response), spec);
+    }
+
+    #[test]
+    fn read_yaml_defaults() {
+        let spec: ProtectionSpec =
+            serde_yaml::from_str("required_reviews: {}\nlinear_history: true\n").unwrap();
+
+        assert_eq!(spec.required_reviews, Some(ReviewsSpec::default()));
+        assert!(spec.linear_history);
+        assert!(!spec.enforce_admins);
+    }
+}
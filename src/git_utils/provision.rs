@@ -0,0 +1,450 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use octorust::types::{
+    FullRepository, Order, ReposCreateInOrgRequestVisibility, ReposListOrgSort, ReposListOrgType,
+};
+use octorust::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::cli_in::set_vars::LabelColor;
+use crate::cli_parse::entities::{CreateRepoArgs, EditRepoArgs};
+use crate::git_utils::labels::{self, LabelChange, LabelSpec};
+use crate::git_utils::repo_info::{Repo, RepoInfo, RepoName, RepoOwner};
+use crate::git_utils::repos;
+use crate::git_utils::spec_diff::{self, FieldChange};
+use crate::git_utils::teams;
+
+const TEAM_PERMISSIONS: [&str; 5] = ["pull", "triage", "push", "maintain", "admin"];
+
+/// Repos of one org as they must be on github
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    pub org: String,
+    pub repos: Vec<RepoSpec>,
+}
+
+/// Repo in manifest. Not set fields are not managed and are not changed
+#[derive(Debug, Clone, Deserialize)]
+pub struct RepoSpec {
+    pub name: String,
+    #[serde(flatten)]
+    pub settings: RepoSettings,
+    pub topics: Option<Vec<String>>,
+    /// Team slug to permission. Teams which are not listed lose access
+    pub teams: Option<BTreeMap<String, String>>,
+    /// Labels are created and updated, other labels are kept
+    pub labels: Option<Vec<LabelSpec>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RepoSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_issues: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_projects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_wiki: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_template: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_auto_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_merge_commit: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_rebase_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_squash_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_branch_on_merge: Option<bool>,
+}
+
+/// Team access change, None is no access
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeamChange {
+    pub slug: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// One change needed to bring org repos to manifest
+#[derive(Debug, Clone)]
+pub enum RepoChange {
+    Create(RepoSpec),
+    Update {
+        spec: RepoSpec,
+        settings: Vec<FieldChange>,
+        topics: Option<(Vec<String>, Vec<String>)>,
+        teams: Vec<TeamChange>,
+        labels: Vec<LabelChange>,
+    },
+    Archive(String),
+}
+
+// Read yaml manifest and check team permissions and label colors
+pub fn read_file(path: &Path) -> Result<Manifest, Box<dyn Error>> {
+    let content = fs::read_to_string(path)?;
+    let mut manifest: Manifest = serde_yaml::from_str(&content)?;
+
+    for spec in &mut manifest.repos {
+        for (slug, permission) in spec.teams.iter().flatten() {
+            if !TEAM_PERMISSIONS.contains(&permission.as_str()) {
+                return Err(format!(
+                    "Repo '{}', team '{}': permission can be only {}",
+                    spec.name,
+                    slug,
+                    TEAM_PERMISSIONS.join(", ")
+                )
+                .into());
+            }
+        }
+        for label in spec.labels.iter_mut().flatten() {
+            label.color = LabelColor::from_str(&label.color)
+                .map_err(|er| format!("Repo '{}', label '{}': {er}", spec.name, label.name))?
+                .0;
+        }
+    }
+    Ok(manifest)
+}
+
+// Changes for all repos in manifest. With `prune` not archived org repos missing in manifest are archived
+pub async fn plan(
+    github_client: &Client,
+    manifest: &Manifest,
+    prune: bool,
+) -> Result<Vec<RepoChange>, Box<dyn Error>> {
+    let org_repos = repos::get_all_from_org(
+        github_client,
+        &manifest.org,
+        Order::Asc,
+        ReposListOrgType::All,
+        ReposListOrgSort::FullName,
+    )
+    .await?;
+
+    let mut changes = Vec::new();
+    for spec in &manifest.repos {
+        if !org_repos.iter().any(|r| r.name == spec.name) {
+            changes.push(RepoChange::Create(spec.clone()));
+            continue;
+        }
+
+        let repo_info = repo_info(&manifest.org, &spec.name)?;
+        if let Some(change) = plan_repo(github_client, &repo_info, spec).await? {
+            changes.push(change);
+        }
+    }
+
+    if prune {
+        for repo in org_repos {
+            if !repo.archived && !manifest.repos.iter().any(|s| s.name == repo.name) {
+                changes.push(RepoChange::Archive(repo.name));
+            }
+        }
+    }
+    Ok(changes)
+}
+
+// Changes for existing repo, None if it's up to date
+pub async fn plan_repo(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    spec: &RepoSpec,
+) -> Result<Option<RepoChange>, Box<dyn Error>> {
+    let current = repos::get(github_client, repo_info).await?;
+
+    let settings = spec_diff::diff(
+        &current_settings(&current),
+        &serde_json::to_value(&spec.settings)?,
+    );
+
+    let topics = match &spec.topics {
+        Some(wanted) if !same_set(&current.topics, wanted) => {
+            Some((current.topics.clone(), wanted.clone()))
+        }
+        _ => None,
+    };
+
+    let teams = match &spec.teams {
+        Some(wanted) => {
+            let current_teams = teams::get_repo_teams(github_client, repo_info).await?;
+            plan_teams(&current_teams, wanted)
+        }
+        None => Vec::new(),
+    };
+
+    let labels = match &spec.labels {
+        Some(wanted) => {
+            let current_labels: Vec<LabelSpec> = labels::get_all(github_client, repo_info)
+                .await?
+                .into_iter()
+                .map(LabelSpec::from)
+                .collect();
+            labels::plan(&current_labels, wanted, false)
+        }
+        None => Vec::new(),
+    };
+
+    if settings.is_empty() && topics.is_none() && teams.is_empty() && labels.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(RepoChange::Update {
+        spec: spec.clone(),
+        settings,
+        topics,
+        teams,
+        labels,
+    }))
+}
+
+pub fn plan_teams(
+    current: &BTreeMap<String, String>,
+    wanted: &BTreeMap<String, String>,
+) -> Vec<TeamChange> {
+    let mut changes = Vec::new();
+
+    for (slug, permission) in wanted {
+        if current.get(slug) != Some(permission) {
+            changes.push(TeamChange {
+                slug: slug.clone(),
+                old: current.get(slug).cloned(),
+                new: Some(permission.clone()),
+            });
+        }
+    }
+    for (slug, permission) in current {
+        if !wanted.contains_key(slug) {
+            changes.push(TeamChange {
+                slug: slug.clone(),
+                old: Some(permission.clone()),
+                new: None,
+            });
+        }
+    }
+    changes
+}
+
+pub async fn apply(
+    github_client: &Client,
+    org: &str,
+    changes: &[RepoChange],
+) -> Result<(), Box<dyn Error>> {
+    for change in changes {
+        match change {
+            RepoChange::Create(spec) => {
+                let repo_info = repo_info(org, &spec.name)?;
+                create(github_client, &repo_info, spec).await?;
+
+                // New repo gets the rest (topics, teams, labels) as update
+                if let Some(update) = plan_repo(github_client, &repo_info, spec).await? {
+                    apply_update(github_client, &repo_info, &update).await?;
+                }
+            }
+            RepoChange::Update { spec, .. } => {
+                let repo_info = repo_info(org, &spec.name)?;
+                apply_update(github_client, &repo_info, change).await?;
+            }
+            RepoChange::Archive(name) => {
+                let repo_info = repo_info(org, name)?;
+                repos::set_archived(github_client, &repo_info, true).await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn create(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    spec: &RepoSpec,
+) -> Result<String, Box<dyn Error>> {
+    let settings = &spec.settings;
+    let command_args = CreateRepoArgs {
+        allow_auto_merge: settings.allow_auto_merge,
+        allow_merge_commit: settings.allow_merge_commit,
+        allow_rebase_merge: settings.allow_rebase_merge,
+        allow_squash_merge: settings.allow_squash_merge,
+        // Repo needs first commit to have default branch
+        auto_init: Some(true),
+        delete_branch_on_merge: settings.delete_branch_on_merge,
+        has_issues: settings.has_issues,
+        has_projects: settings.has_projects,
+        has_wiki: settings.has_wiki,
+        is_template: settings.is_template,
+        private: settings.private,
+        description: settings.description.clone().unwrap_or_default(),
+        gitignore_template: String::new(),
+        homepage: settings.homepage.clone().unwrap_or_default(),
+        license_template: String::new(),
+        name: spec.name.clone(),
+    };
+    let visibility = settings.private.map(|private| match private {
+        true => ReposCreateInOrgRequestVisibility::Private,
+        false => ReposCreateInOrgRequestVisibility::Public,
+    });
+
+    repos::create_in_org(
+        github_client,
+        repo_info.clone(),
+        command_args,
+        "",
+        visibility,
+    )
+    .await
+}
+
+async fn apply_update(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    change: &RepoChange,
+) -> Result<(), Box<dyn Error>> {
+    let RepoChange::Update {
+        spec,
+        settings,
+        topics,
+        teams: team_changes,
+        labels: label_changes,
+    } = change
+    else {
+        return Ok(());
+    };
+
+    if !settings.is_empty() || topics.is_some() {
+        repos::update(repo_info, edit_args(spec)).await?;
+    }
+    for team in team_changes {
+        match &team.new {
+            Some(permission) => {
+                teams::set_repo_permission(github_client, repo_info, &team.slug, permission).await?
+            }
+            None => teams::remove_repo(github_client, repo_info, &team.slug).await?,
+        };
+    }
+    labels::apply(github_client, repo_info, label_changes).await?;
+    Ok(())
+}
+
+fn edit_args(spec: &RepoSpec) -> EditRepoArgs {
+    let settings = spec.settings.clone();
+
+    EditRepoArgs {
+        allow_auto_merge: settings.allow_auto_merge,
+        allow_merge_commit: settings.allow_merge_commit,
+        allow_rebase_merge: settings.allow_rebase_merge,
+        allow_squash_merge: settings.allow_squash_merge,
+        delete_branch_on_merge: settings.delete_branch_on_merge,
+        has_issues: settings.has_issues,
+        has_projects: settings.has_projects,
+        has_wiki: settings.has_wiki,
+        is_template: settings.is_template,
+        private: settings.private,
+        description: settings.description,
+        homepage: settings.homepage,
+        default_branch: settings.default_branch,
+        topics: spec.topics.clone(),
+    }
+}
+
+// Same field names as in RepoSettings, so only managed fields are compared
+fn current_settings(repo: &FullRepository) -> Value {
+    json!({
+        "description": repo.description,
+        "homepage": repo.homepage,
+        "private": repo.private,
+        "default_branch": repo.default_branch,
+        "has_issues": repo.has_issues,
+        "has_projects": repo.has_projects,
+        "has_wiki": repo.has_wiki,
+        "is_template": repo.is_template,
+        "allow_auto_merge": repo.allow_auto_merge,
+        "allow_merge_commit": repo.allow_merge_commit,
+        "allow_rebase_merge": repo.allow_rebase_merge,
+        "allow_squash_merge": repo.allow_squash_merge,
+        "delete_branch_on_merge": repo.delete_branch_on_merge,
+    })
+}
+
+fn same_set(current: &[String], wanted: &[String]) -> bool {
+    let mut current = current.to_vec();
+    let mut wanted = wanted.to_vec();
+    current.sort();
+    wanted.sort();
+    current == wanted
+}
+
+fn repo_info(org: &str, name: &str) -> Result<RepoInfo, Box<dyn Error>> {
+    Ok(RepoInfo::new(
+        Repo::Input,
+        Some(org.parse::<RepoOwner>()?),
+        Some(name.parse::<RepoName>()?),
+    )?)
+}
+
+#[cfg(test)]
+mod provision_tests {
+    use super::*;
+
+    #[test]
+    fn plan_teams_test() {
+        let current = BTreeMap::from([
+            ("core".to_string(), "push".to_string()),
+            ("ops".to_string(), "admin".to_string()),
+            ("old".to_string(), "pull".to_string()),
+        ]);
+        let wanted = BTreeMap::from([
+            ("core".to_string(), "push".to_string()),
+            ("ops".to_string(), "maintain".to_string()),
+            ("qa".to_string(), "triage".to_string()),
+        ]);
+
+        assert_eq!(
+            plan_teams(&current, &wanted),
+            vec![
+                TeamChange {
+                    slug: "ops".to_string(),
+                    old: Some("admin".to_string()),
+                    new: Some("maintain".to_string()),
+                },
+                TeamChange {
+                    slug: "qa".to_string(),
+                    old: None,
+                    new: Some("triage".to_string()),
+                },
+                TeamChange {
+                    slug: "old".to_string(),
+                    old: Some("pull".to_string()),
+                    new: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn read_manifest_settings() {
+        let manifest: Manifest = serde_yaml::from_str(
+            "org: my-org\nrepos:\n  - name: api\n    private: true\n    topics: [rust]\n",
+        )
+        .unwrap();
+        let spec = &manifest.repos[0];
+
+        assert_eq!(spec.settings.private, Some(true));
+        assert_eq!(spec.topics, Some(vec!["rust".to_string()]));
+        assert_eq!(
+            serde_json::to_value(&spec.settings).unwrap(),
+            json!({ "private": true })
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use std::error::Error;
+
+use octorust::types::{
+    Comments, DiffEntry, IssuesListState, Order, PullRequestSimple, PullsCreateReviewRequest,
+    PullsCreateReviewRequestEvent, PullsListSort,
+};
+use octorust::Client;
+
+use crate::git_utils::repo_info::RepoInfo;
+
+pub async fn get_list(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    state: IssuesListState,
+    numb_of_page: i64,
+    prs_on_page: i64,
+) -> Result<Vec<PullRequestSimple>, Box<dyn Error>> {
+    let pulls = github_client
+        .pulls()
+        .list(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            state,
+            "",
+            "",
+            PullsListSort::Created,
+            Order::Desc,
+            prs_on_page,
+            numb_of_page,
+        )
+        .await;
+
+    match pulls {
+        Ok(p) => Ok(p.body),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Get changed files of pull request with their patches
+pub async fn get_files(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    number: i64,
+) -> Result<Vec<DiffEntry>, Box<dyn Error>> {
+    let files = github_client
+        .pulls()
+        .list_all_files(&repo_info.get_owner(), &repo_info.get_name(), number)
+        .await;
+
+    match files {
+        Ok(f) => Ok(f.body),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Create review with all inline comments at once
+pub async fn create_review(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    number: i64,
+    body: &str,
+    event: PullsCreateReviewRequestEvent,
+    comments: Vec<Comments>,
+) -> Result<String, Box<dyn Error>> {
+    let request = PullsCreateReviewRequest {
+        body: body.to_owned(),
+        comments,
+        commit_id: String::new(),
+        event: Some(event),
+    };
+
+    let review = github_client
+        .pulls()
+        .create_review(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            number,
+            &request,
+        )
+        .await;
+
+    match review {
+        Ok(_) => Ok("Review submitted".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
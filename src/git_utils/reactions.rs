@@ -0,0 +1,137 @@
+use std::error::Error;
+use std::io;
+
+use octorust::types::{Content, Reaction, ReactionsCreateIssueRequest};
+use octorust::Client;
+
+use crate::cli_in::set_vars::ReactionTarget;
+use crate::git_utils::repo_info::RepoInfo;
+
+// Get reactions of issue/pull request or comment. Content::Noop gives all reactions
+pub async fn get_all(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    target: &ReactionTarget,
+    id: i64,
+    content: Content,
+) -> Result<Vec<Reaction>, Box<dyn Error>> {
+    let owner = repo_info.get_owner();
+    let name = repo_info.get_name();
+    let reactions = github_client.reactions();
+
+    let list = match target {
+        ReactionTarget::Issue => {
+            reactions
+                .list_all_for_issue(&owner, &name, id, content)
+                .await
+        }
+        ReactionTarget::Comment => {
+            reactions
+                .list_all_for_issue_comment(&owner, &name, id, content)
+                .await
+        }
+        ReactionTarget::ReviewComment => {
+            reactions
+                .list_all_for_pull_request_review_comment(&owner, &name, id, content)
+                .await
+        }
+    };
+
+    match list {
+        Ok(l) => Ok(l.body),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+pub async fn create(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    target: &ReactionTarget,
+    id: i64,
+    content: Content,
+) -> Result<String, Box<dyn Error>> {
+    let owner = repo_info.get_owner();
+    let name = repo_info.get_name();
+    let reactions = github_client.reactions();
+    let request = ReactionsCreateIssueRequest { content };
+
+    let result = match target {
+        ReactionTarget::Issue => {
+            reactions
+                .create_for_issue(&owner, &name, id, &request)
+                .await
+        }
+        ReactionTarget::Comment => {
+            reactions
+                .create_for_issue_comment(&owner, &name, id, &request)
+                .await
+        }
+        ReactionTarget::ReviewComment => {
+            reactions
+                .create_for_pull_request_review_comment(&owner, &name, id, &request)
+                .await
+        }
+    };
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Delete reaction with content made by authenticated user
+pub async fn delete_own(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    target: &ReactionTarget,
+    id: i64,
+    content: Content,
+) -> Result<String, Box<dyn Error>> {
+    let me = github_client
+        .users()
+        .get_authenticated_public_user()
+        .await?
+        .body
+        .login;
+
+    let list = get_all(github_client, repo_info, target, id, content).await?;
+    let reaction_id = match list
+        .iter()
+        .find(|r| r.user.as_ref().is_some_and(|u| u.login == me))
+    {
+        Some(r) => r.id,
+        None => {
+            return Err(Box::new(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Reaction of current user not found",
+            )))
+        }
+    };
+
+    let owner = repo_info.get_owner();
+    let name = repo_info.get_name();
+    let reactions = github_client.reactions();
+
+    let result = match target {
+        ReactionTarget::Issue => {
+            reactions
+                .delete_for_issue(&owner, &name, id, reaction_id)
+                .await
+        }
+        ReactionTarget::Comment => {
+            reactions
+                .delete_for_issue_comment(&owner, &name, id, reaction_id)
+                .await
+        }
+        ReactionTarget::ReviewComment => {
+            reactions
+                .delete_for_pull_request_comment(&owner, &name, id, reaction_id)
+                .await
+        }
+    };
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
@@ -1,11 +1,15 @@
 use std::error::Error;
 
 use octorust::{
-    types::{Release, ReposCreateReleaseRequest},
+    types::{GitCreateRefRequest, Release, ReposCreateReleaseRequest},
     Client,
 };
+use reqwest::Method;
+use serde_json::{json, Map, Value};
 
-use crate::{cli_parse::entities::CreateReleaseArgs, git_utils::repo_info::RepoInfo};
+use crate::cli_parse::entities::{CreateReleaseArgs, EditReleaseArgs};
+use crate::git_utils::repo_info::RepoInfo;
+use crate::git_utils::rest;
 
 pub async fn create(
     github_client: &Client,
@@ -33,6 +37,33 @@ pub async fn create(
     }
 }
 
+// Release notes made by github from pull requests since previous release.
+// Octorust has no such endpoint, so raw request is used
+pub async fn generate_notes(
+    repo_info: &RepoInfo,
+    tag_name: &str,
+    target_commitish: &str,
+    previous_tag: Option<&str>,
+) -> Result<String, Box<dyn Error>> {
+    let mut request = Map::new();
+    request.insert("tag_name".to_string(), json!(tag_name));
+    if !target_commitish.is_empty() {
+        request.insert("target_commitish".to_string(), json!(target_commitish));
+    }
+    if let Some(previous) = previous_tag {
+        request.insert("previous_tag_name".to_string(), json!(previous));
+    }
+
+    let path = format!(
+        "/repos/{}/{}/releases/generate-notes",
+        repo_info.get_owner(),
+        repo_info.get_name()
+    );
+    let notes = rest::send(Method::POST, &path, Some(&Value::Object(request))).await?;
+
+    Ok(notes["body"].as_str().unwrap_or_default().to_string())
+}
+
 pub async fn get_latest(
     github_client: &Client,
     repo_info: RepoInfo,
@@ -79,3 +110,160 @@ pub async fn get_by_id(
         Err(er) => Err(Box::new(er)),
     }
 }
+
+pub async fn get_all(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+) -> Result<Vec<Release>, Box<dyn Error>> {
+    let result = github_client
+        .repos()
+        .list_all_releases(&repo_info.get_owner(), &repo_info.get_name())
+        .await;
+
+    match result {
+        Ok(r) => Ok(r.body),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+pub async fn get_page(
+    github_client: &Client,
+    repo_info: RepoInfo,
+    numb_of_page: i64,
+    rel_on_page: i64,
+) -> Result<Vec<Release>, Box<dyn Error>> {
+    let result = github_client
+        .repos()
+        .list_releases(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            rel_on_page,
+            numb_of_page,
+        )
+        .await;
+
+    match result {
+        Ok(r) => Ok(r.body),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Octorust update request needs tag and has no make_latest, so raw request is used.
+// None fields are not changed
+pub async fn update(
+    repo_info: RepoInfo,
+    id: i64,
+    command_args: EditReleaseArgs,
+) -> Result<String, Box<dyn Error>> {
+    let mut request = Map::new();
+    if let Some(name) = command_args.name {
+        request.insert("name".to_string(), json!(name));
+    }
+    if let Some(body) = command_args.body {
+        request.insert("body".to_string(), json!(body));
+    }
+    if let Some(draft) = command_args.draft {
+        request.insert("draft".to_string(), json!(draft));
+    }
+    if let Some(prerelease) = command_args.prerelease {
+        request.insert("prerelease".to_string(), json!(prerelease));
+    }
+    if let Some(make_latest) = command_args.make_latest {
+        request.insert("make_latest".to_string(), json!(make_latest));
+    }
+
+    let path = format!(
+        "/repos/{}/{}/releases/{}",
+        repo_info.get_owner(),
+        repo_info.get_name(),
+        id
+    );
+    let release = rest::send(Method::PATCH, &path, Some(&Value::Object(request))).await?;
+
+    Ok(release["html_url"].as_str().unwrap_or_default().to_string())
+}
+
+pub async fn delete(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    id: i64,
+) -> Result<String, Box<dyn Error>> {
+    let result = github_client
+        .repos()
+        .delete_release(&repo_info.get_owner(), &repo_info.get_name(), id)
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+pub async fn tag_exists(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    tag: &str,
+) -> Result<bool, Box<dyn Error>> {
+    // Matching refs are found by prefix, so `v1.0` matches `v1.0.1` too
+    let result = github_client
+        .git()
+        .list_all_matching_refs(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            &format!("tags/{tag}"),
+        )
+        .await;
+
+    match result {
+        Ok(r) => Ok(r
+            .body
+            .iter()
+            .any(|git_ref| git_ref.ref_ == format!("refs/tags/{tag}"))),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Lightweight tag on commit
+pub async fn create_tag(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    tag: &str,
+    sha: &str,
+) -> Result<String, Box<dyn Error>> {
+    let request = GitCreateRefRequest {
+        key: String::new(),
+        ref_: format!("refs/tags/{tag}"),
+        sha: sha.to_string(),
+    };
+
+    let result = github_client
+        .git()
+        .create_ref(&repo_info.get_owner(), &repo_info.get_name(), &request)
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Deleting release keeps its tag, so tag is deleted separately
+pub async fn delete_tag(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    tag: &str,
+) -> Result<String, Box<dyn Error>> {
+    let result = github_client
+        .git()
+        .delete_ref(
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            &format!("tags/{tag}"),
+        )
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
@@ -153,6 +153,11 @@ impl RepoInfo {
         self.ssh.clone().0
     }
 
+    /// Https clone url like `https://github.com/owner/repo.git`
+    pub fn get_https(&self) -> String {
+        format!("{}.git", self.url.0.trim_end_matches('/'))
+    }
+
     fn create_repo_info(
         owner: Option<RepoOwner>,
         name: Option<RepoName>,
@@ -201,6 +206,22 @@ impl RepoInfo {
     }
 }
 
+impl FromStr for RepoInfo {
+    type Err = String;
+
+    /// Parse repo from `owner/repo` string
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().split_once('/') {
+            Some((owner, name)) => Self::create_repo_info(
+                Some(RepoOwner::from_str(owner)?),
+                Some(RepoName::from_str(name)?),
+            )
+            .map_err(|er| er.to_string()),
+            None => Err("Repo must be in format 'owner/repo'".to_string()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod repo_info_tests {
     use super::*;
@@ -303,4 +324,21 @@ mod repo_info_tests {
         let repo_current = RepoInfo::new(Repo::Input, owner, name);
         assert_eq!(repo_current.unwrap_err().kind(), io::ErrorKind::NotFound);
     }
+
+    // Tests parse from `owner/repo`
+    #[rstest]
+    #[case("aragami3070/github-cli", Ok(("aragami3070", "github-cli")))]
+    #[case(" SE-legacy/kg-exam-4sem ", Ok(("SE-legacy", "kg-exam-4sem")))]
+    #[case("github-cli", Err("Repo must be in format 'owner/repo'"))]
+    #[case("/github-cli", Err("Repo owner cannot be empty"))]
+    #[case("aragami3070/github-cli/", Err("Repo name cannot contain '/'"))]
+    fn repo_info_from_str(#[case] input: &str, #[case] expected: Result<(&str, &str), &str>) {
+        let repo = RepoInfo::from_str(input).map(|r| (r.get_owner(), r.get_name()));
+        assert_eq!(
+            repo,
+            expected
+                .map(|(o, n)| (o.to_string(), n.to_string()))
+                .map_err(|er| er.to_string())
+        );
+    }
 }
@@ -1,16 +1,23 @@
 use std::error::Error;
+use std::io;
 
 use octorust::{
     types::{
-        MinimalRepository, Order, ReposCreateForkRequest, ReposCreateInOrgRequest,
+        FullRepository, MinimalRepository, Order, ReposCreateForkRequest, ReposCreateInOrgRequest,
         ReposCreateInOrgRequestVisibility, ReposCreateRequest, ReposCreateUsingTemplateRequest,
-        ReposListOrgSort, ReposListOrgType, ReposListUserType,
+        ReposListOrgSort, ReposListOrgType, ReposListUserType, ReposUpdateRequest,
     },
     Client,
 };
+use reqwest::{Method, StatusCode};
+use serde_json::{json, Map, Value};
 
-use crate::cli_parse::entities::{CreateRepoArgs, CreateRepoFromTemplateArgs};
-use crate::git_utils::{repo_info::RepoInfo, teams::get_id};
+use crate::cli_parse::entities::{CreateRepoArgs, CreateRepoFromTemplateArgs, EditRepoArgs};
+use crate::git_utils::{
+    repo_info::{RepoInfo, RepoName},
+    rest,
+    teams::get_id,
+};
 
 pub async fn create_for_authenticated_user(
     github_client: &Client,
@@ -55,9 +62,15 @@ pub async fn create_in_org(
     team_name: &str,
     visibility: Option<ReposCreateInOrgRequestVisibility>,
 ) -> Result<String, Box<dyn Error>> {
-    let team = get_id(github_client, &repo_info.get_owner(), team_name).await?;
-
-    let team_id = team.id;
+    // Empty team name gives repo without team access
+    let team_id = match team_name {
+        "" => 0,
+        name => {
+            get_id(github_client, &repo_info.get_owner(), name)
+                .await?
+                .id
+        }
+    };
 
     let request = ReposCreateInOrgRequest {
         allow_auto_merge: command_args.allow_auto_merge,
@@ -175,3 +188,195 @@ pub async fn get_all_from_user(
         Err(er) => Err(Box::new(er)),
     }
 }
+
+// Repos which token owner can access: own, collaborated and from orgs, private ones too
+pub async fn get_all_for_authenticated_user() -> Result<Vec<MinimalRepository>, Box<dyn Error>> {
+    let mut all_repos = Vec::new();
+    let mut page = 1;
+    loop {
+        let response = rest::send_with_query(
+            Method::GET,
+            "/user/repos",
+            &[
+                ("sort", "full_name"),
+                ("per_page", "100"),
+                ("page", &page.to_string()),
+            ],
+            None,
+        )
+        .await?;
+
+        let items = response.as_array().cloned().unwrap_or_default();
+        let last_page = items.len() < 100;
+        for item in items {
+            all_repos.push(serde_json::from_value(item)?);
+        }
+        if last_page {
+            break;
+        }
+        page += 1;
+    }
+    Ok(all_repos)
+}
+
+pub async fn get(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+) -> Result<FullRepository, Box<dyn Error>> {
+    let result = github_client
+        .repos()
+        .get(&repo_info.get_owner(), &repo_info.get_name())
+        .await;
+
+    match result {
+        Ok(r) => Ok(r.body),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Octorust update request skips empty strings, so description and homepage can't be cleared
+// with it. Raw request is used, None fields are not changed
+pub async fn update(
+    repo_info: &RepoInfo,
+    command_args: EditRepoArgs,
+) -> Result<String, Box<dyn Error>> {
+    let mut request = Map::new();
+    let toggles = [
+        ("allow_auto_merge", command_args.allow_auto_merge),
+        ("allow_merge_commit", command_args.allow_merge_commit),
+        ("allow_rebase_merge", command_args.allow_rebase_merge),
+        ("allow_squash_merge", command_args.allow_squash_merge),
+        (
+            "delete_branch_on_merge",
+            command_args.delete_branch_on_merge,
+        ),
+        ("has_issues", command_args.has_issues),
+        ("has_projects", command_args.has_projects),
+        ("has_wiki", command_args.has_wiki),
+        ("is_template", command_args.is_template),
+        ("private", command_args.private),
+    ];
+    for (field, value) in toggles {
+        if let Some(v) = value {
+            request.insert(field.to_string(), json!(v));
+        }
+    }
+    let texts = [
+        ("description", command_args.description),
+        ("homepage", command_args.homepage),
+        ("default_branch", command_args.default_branch),
+    ];
+    for (field, value) in texts {
+        if let Some(v) = value {
+            request.insert(field.to_string(), json!(v));
+        }
+    }
+
+    let path = format!("/repos/{}/{}", repo_info.get_owner(), repo_info.get_name());
+    let repo = rest::send(Method::PATCH, &path, Some(&Value::Object(request))).await?;
+
+    // Topics have own endpoint, empty list removes all topics
+    if let Some(topics) = command_args.topics {
+        rest::send(
+            Method::PUT,
+            &format!("{path}/topics"),
+            Some(&json!({ "names": topics })),
+        )
+        .await?;
+    }
+
+    Ok(repo["html_url"].as_str().unwrap_or_default().to_string())
+}
+
+// Github redirects old name to new one, but local remotes should be updated
+pub async fn rename(repo_info: &RepoInfo, new_name: &RepoName) -> Result<String, Box<dyn Error>> {
+    let path = format!("/repos/{}/{}", repo_info.get_owner(), repo_info.get_name());
+    let repo = rest::send(
+        Method::PATCH,
+        &path,
+        Some(&json!({ "name": new_name.trim() })),
+    )
+    .await?;
+
+    Ok(repo["html_url"].as_str().unwrap_or_default().to_string())
+}
+
+pub async fn delete(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+) -> Result<String, Box<dyn Error>> {
+    let result = github_client
+        .repos()
+        .delete(&repo_info.get_owner(), &repo_info.get_name())
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+// Merge upstream changes into fork branch. Github answers with conflict if branch has own commits
+pub async fn sync_fork(repo_info: &RepoInfo, branch: &str) -> Result<String, Box<dyn Error>> {
+    let path = format!(
+        "/repos/{}/{}/merge-upstream",
+        repo_info.get_owner(),
+        repo_info.get_name()
+    );
+
+    match rest::send(Method::POST, &path, Some(&json!({ "branch": branch }))).await {
+        Ok(result) => Ok(result["message"].as_str().unwrap_or_default().to_string()),
+        Err(er) => match er.downcast_ref::<rest::ApiError>() {
+            Some(api_er) if api_er.status == StatusCode::CONFLICT => {
+                Err(Box::new(io::Error::other(format!(
+                    "Branch {branch} has diverged from upstream, it can't be fast-forwarded. \
+                     Merge or rebase it locally"
+                ))))
+            }
+            _ => Err(er),
+        },
+    }
+}
+
+pub async fn get_default_branch(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+) -> Result<String, Box<dyn Error>> {
+    Ok(get(github_client, repo_info).await?.default_branch)
+}
+
+pub async fn set_archived(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    archived: bool,
+) -> Result<String, Box<dyn Error>> {
+    let request = ReposUpdateRequest {
+        allow_auto_merge: None,
+        allow_merge_commit: None,
+        allow_rebase_merge: None,
+        allow_squash_merge: None,
+        archived: Some(archived),
+        default_branch: String::new(),
+        delete_branch_on_merge: None,
+        description: String::new(),
+        has_issues: None,
+        has_projects: None,
+        has_wiki: None,
+        homepage: String::new(),
+        is_template: None,
+        name: String::new(),
+        private: None,
+        security_and_analysis: None,
+        visibility: None,
+    };
+
+    let result = github_client
+        .repos()
+        .update(&repo_info.get_owner(), &repo_info.get_name(), &request)
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
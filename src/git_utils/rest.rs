@@ -0,0 +1,156 @@
+use std::error::Error;
+use std::io::Write;
+use std::{env, fmt, io};
+
+use reqwest::{Body, Method, RequestBuilder, Response, StatusCode};
+use serde_json::Value;
+
+const API_URL: &str = "https://api.github.com";
+
+// Not success answer of github api, status is kept to tell apart expected errors
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub message: String,
+}
+
+impl Error for ApiError {}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "github api returned {}: {}", self.status, self.message)
+    }
+}
+
+// Request to github api for endpoints and fields which octorust doesn't support
+pub async fn send(
+    method: Method,
+    path: &str,
+    body: Option<&Value>,
+) -> Result<Value, Box<dyn Error>> {
+    send_with_query(method, path, &[], body).await
+}
+
+// Same as `send`, query params are url encoded
+pub async fn send_with_query(
+    method: Method,
+    path: &str,
+    query: &[(&str, &str)],
+    body: Option<&Value>,
+) -> Result<Value, Box<dyn Error>> {
+    let mut request = request(
+        method,
+        &format!("{API_URL}{path}"),
+        "application/vnd.github+json",
+    )?
+    .query(query);
+    if let Some(b) = body {
+        request = request.json(b);
+    }
+
+    parse_response(request.send().await?).await
+}
+
+// Upload release asset. `upload_url` is given in release as template like `.../assets{?name,label}`.
+// Github needs length of streamed body up front
+pub async fn upload(
+    upload_url: &str,
+    name: &str,
+    content_type: &str,
+    body: Body,
+    size: u64,
+) -> Result<Value, Box<dyn Error>> {
+    let url = upload_url.split('{').next().unwrap_or(upload_url);
+
+    let response = request(Method::POST, url, "application/vnd.github+json")?
+        .query(&[("name", name)])
+        .header("Content-Type", content_type)
+        .header("Content-Length", size)
+        .body(body)
+        .send()
+        .await?;
+
+    parse_response(response).await
+}
+
+// Stream file from github to writer, `on_progress` gets downloaded and total bytes (0 if unknown)
+pub async fn download(
+    url: &str,
+    writer: &mut impl Write,
+    mut on_progress: impl FnMut(u64, u64),
+) -> Result<(), Box<dyn Error>> {
+    let mut response = request(Method::GET, url, "application/octet-stream")?
+        .send()
+        .await?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(api_error(status, response.text().await?));
+    }
+
+    let total = response.content_length().unwrap_or_default();
+    let mut downloaded = 0;
+    while let Some(chunk) = response.chunk().await? {
+        writer.write_all(&chunk)?;
+        downloaded += chunk.len() as u64;
+        on_progress(downloaded, total);
+    }
+    Ok(())
+}
+
+// Query to github graphql api, returns `data` of response
+pub async fn graphql(query: &str, variables: Value) -> Result<Value, Box<dyn Error>> {
+    let body = serde_json::json!({
+        "query": query,
+        "variables": variables,
+    });
+
+    let response = send(Method::POST, "/graphql", Some(&body)).await?;
+
+    if let Some(errors) = response["errors"].as_array() {
+        let messages: Vec<&str> = errors
+            .iter()
+            .filter_map(|er| er["message"].as_str())
+            .collect();
+        return Err(Box::new(io::Error::other(format!(
+            "github graphql returned errors: {}",
+            messages.join("; ")
+        ))));
+    }
+    Ok(response["data"].clone())
+}
+
+fn request(method: Method, url: &str, accept: &str) -> Result<RequestBuilder, Box<dyn Error>> {
+    let token = env::var("GITHUB_TOKEN")?;
+
+    Ok(reqwest::Client::new()
+        .request(method, url)
+        .bearer_auth(token)
+        .header("User-Agent", "github-cli")
+        .header("Accept", accept)
+        .header("X-GitHub-Api-Version", "2022-11-28"))
+}
+
+async fn parse_response(response: Response) -> Result<Value, Box<dyn Error>> {
+    let status = response.status();
+    let text = response.text().await?;
+
+    if !status.is_success() {
+        return Err(api_error(status, text));
+    }
+
+    if text.is_empty() {
+        Ok(Value::Null)
+    } else {
+        Ok(serde_json::from_str(&text)?)
+    }
+}
+
+fn api_error(status: StatusCode, text: String) -> Box<dyn Error> {
+    let message = serde_json::from_str::<Value>(&text)
+        .ok()
+        .and_then(|v| v["message"].as_str().map(|m| m.to_string()))
+        .unwrap_or(text);
+
+    Box::new(ApiError { status, message })
+}
@@ -0,0 +1,186 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use reqwest::Method;
+use serde_json::Value;
+
+use crate::git_utils::repo_info::RepoInfo;
+use crate::git_utils::rest;
+use crate::git_utils::spec_diff::{self, FieldChange};
+
+/// One change needed to bring repo rulesets to wanted state.
+/// Rulesets are matched by name
+#[derive(Debug, Clone, PartialEq)]
+pub enum RulesetChange {
+    Create(Value),
+    Update {
+        id: i64,
+        name: String,
+        fields: Vec<FieldChange>,
+        wanted: Value,
+    },
+    Delete {
+        id: i64,
+        name: String,
+    },
+}
+
+// Short info of repo rulesets: id, name, target and enforcement.
+// Org rulesets are not included, they can't be changed through repo
+pub async fn get_all(repo_info: &RepoInfo) -> Result<Vec<Value>, Box<dyn Error>> {
+    let rulesets = rest::send_with_query(
+        Method::GET,
+        &rulesets_path(repo_info),
+        &[("per_page", "100"), ("includes_parents", "false")],
+        None,
+    )
+    .await?;
+
+    Ok(rulesets.as_array().cloned().unwrap_or_default())
+}
+
+// Ruleset with rules, conditions and bypass actors
+pub async fn get(repo_info: &RepoInfo, id: i64) -> Result<Value, Box<dyn Error>> {
+    rest::send(
+        Method::GET,
+        &format!("{}/{}", rulesets_path(repo_info), id),
+        None,
+    )
+    .await
+}
+
+// Yaml file with list of rulesets in github api format (`name`, `target`, `enforcement`, `rules`, ...)
+pub fn read_file(path: &Path) -> Result<Vec<Value>, Box<dyn Error>> {
+    let content = fs::read_to_string(path)?;
+    let rulesets: Vec<Value> = serde_yaml::from_str(&content)?;
+
+    for ruleset in &rulesets {
+        if ruleset["name"].as_str().is_none() {
+            return Err(format!("Ruleset without name in {}", path.display()).into());
+        }
+    }
+    Ok(rulesets)
+}
+
+// Current rulesets must be full (from `get`), only fields set in wanted rulesets are compared.
+// Rulesets of org are skipped: they are not matched by name and not pruned
+pub fn plan(current: &[Value], wanted: &[Value], prune: bool) -> Vec<RulesetChange> {
+    let current: Vec<&Value> = current.iter().filter(|r| is_repo_ruleset(r)).collect();
+    let mut changes = Vec::new();
+
+    for new in wanted {
+        match current.iter().find(|old| old["name"] == new["name"]) {
+            None => changes.push(RulesetChange::Create(new.clone())),
+            Some(old) => {
+                let fields = spec_diff::diff(old, new);
+                if !fields.is_empty() {
+                    changes.push(RulesetChange::Update {
+                        id: old["id"].as_i64().unwrap_or_default(),
+                        name: name_of(old),
+                        fields,
+                        wanted: new.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    if prune {
+        for old in &current {
+            if !wanted.iter().any(|new| new["name"] == old["name"]) {
+                changes.push(RulesetChange::Delete {
+                    id: old["id"].as_i64().unwrap_or_default(),
+                    name: name_of(old),
+                });
+            }
+        }
+    }
+    changes
+}
+
+pub async fn apply(repo_info: &RepoInfo, changes: &[RulesetChange]) -> Result<(), Box<dyn Error>> {
+    let path = rulesets_path(repo_info);
+
+    for change in changes {
+        match change {
+            RulesetChange::Create(new) => {
+                rest::send(Method::POST, &path, Some(new)).await?;
+            }
+            RulesetChange::Update { id, wanted, .. } => {
+                rest::send(Method::PUT, &format!("{path}/{id}"), Some(wanted)).await?;
+            }
+            RulesetChange::Delete { id, .. } => {
+                rest::send(Method::DELETE, &format!("{path}/{id}"), None).await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// Rulesets without `source_type` (short info of old api) are taken as repo ones
+fn is_repo_ruleset(ruleset: &Value) -> bool {
+    ruleset["source_type"]
+        .as_str()
+        .is_none_or(|source_type| source_type == "Repository")
+}
+
+pub fn name_of(ruleset: &Value) -> String {
+    ruleset["name"].as_str().unwrap_or_default().to_string()
+}
+
+fn rulesets_path(repo_info: &RepoInfo) -> String {
+    format!(
+        "/repos/{}/{}/rulesets",
+        repo_info.get_owner(),
+        repo_info.get_name()
+    )
+}
+
+#[cfg(test)]
+mod rulesets_tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn plan_changes() {
+        let current = vec![
+            json!({ "id": 1, "name": "main", "enforcement": "evaluate", "source_type": "Repository", "source": "org/repo" }),
+            json!({ "id": 2, "name": "old", "enforcement": "active" }),
+        ];
+        let wanted = vec![
+            json!({ "name": "main", "enforcement": "active" }),
+            json!({ "name": "tags", "target": "tag" }),
+        ];
+
+        let changes = plan(&current, &wanted, true);
+
+        assert_eq!(changes.len(), 3);
+        assert!(
+            matches!(&changes[0], RulesetChange::Update { id: 1, fields, .. } if fields.len() == 1)
+        );
+        assert_eq!(changes[1], RulesetChange::Create(wanted[1].clone()));
+        assert_eq!(
+            changes[2],
+            RulesetChange::Delete {
+                id: 2,
+                name: "old".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn plan_skips_org_rulesets() {
+        let current = vec![
+            json!({ "id": 5, "name": "main", "enforcement": "active", "source_type": "Organization", "source": "org" }),
+            json!({ "id": 6, "name": "org-only", "source_type": "Organization", "source": "org" }),
+        ];
+        let wanted = vec![json!({ "name": "main", "enforcement": "active" })];
+
+        // Repo ruleset with same name is created, org rulesets are not touched
+        assert_eq!(
+            plan(&current, &wanted, true),
+            vec![RulesetChange::Create(wanted[0].clone())]
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::error::Error;
+use std::time::Duration;
+
+use chrono::Utc;
+use octorust::types::{CodeSearchResultItem, IssueSimple, MinimalRepository, UserSearchResultItem};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+use crate::cli_parse::entities::SearchArgs;
+use crate::git_utils::rest;
+
+// Search api doesn't give more than 1000 results for query
+const MAX_RESULTS: i64 = 1000;
+const MAX_PER_PAGE: i64 = 100;
+
+pub struct SearchResult<T> {
+    pub total_count: i64,
+    pub incomplete_results: bool,
+    pub items: Vec<T>,
+}
+
+// Join search words with qualifiers. Empty qualifiers are skipped, values with spaces are quoted
+pub fn build_query(words: &[String], qualifiers: &[(&str, String)]) -> String {
+    let mut parts: Vec<String> = words.iter().filter(|w| !w.is_empty()).cloned().collect();
+
+    for (key, value) in qualifiers {
+        if value.is_empty() {
+            continue;
+        }
+        if value.contains(' ') {
+            parts.push(format!("{key}:\"{value}\""));
+        } else {
+            parts.push(format!("{key}:{value}"));
+        }
+    }
+    parts.join(" ")
+}
+
+pub async fn issues(args: &SearchArgs) -> Result<SearchResult<IssueSimple>, Box<dyn Error>> {
+    get_pages("/search/issues", args).await
+}
+
+pub async fn repos(args: &SearchArgs) -> Result<SearchResult<MinimalRepository>, Box<dyn Error>> {
+    get_pages("/search/repositories", args).await
+}
+
+pub async fn code(args: &SearchArgs) -> Result<SearchResult<CodeSearchResultItem>, Box<dyn Error>> {
+    get_pages("/search/code", args).await
+}
+
+pub async fn users(
+    args: &SearchArgs,
+) -> Result<SearchResult<UserSearchResultItem>, Box<dyn Error>> {
+    get_pages("/search/users", args).await
+}
+
+// Get pages until limit of results is reached or there are no more results
+async fn get_pages<T: DeserializeOwned>(
+    path: &str,
+    args: &SearchArgs,
+) -> Result<SearchResult<T>, Box<dyn Error>> {
+    let limit = args.limit.min(MAX_RESULTS);
+    let per_page = limit.min(MAX_PER_PAGE);
+    let per_page_str = per_page.to_string();
+    let order = args.order.to_string();
+
+    let mut result = SearchResult {
+        total_count: 0,
+        incomplete_results: false,
+        items: Vec::new(),
+    };
+    let mut page = 1;
+
+    while (result.items.len() as i64) < limit {
+        wait_for_rate_limit(path).await?;
+
+        let page_str = page.to_string();
+        let mut query = vec![
+            ("q", args.query.as_str()),
+            ("per_page", per_page_str.as_str()),
+            ("page", page_str.as_str()),
+        ];
+        // Without sort github uses best match and ignores order
+        if !args.sort.is_empty() {
+            query.push(("sort", args.sort.as_str()));
+            query.push(("order", order.as_str()));
+        }
+
+        let response = rest::send_with_query(Method::GET, path, &query, None).await?;
+
+        result.total_count = response["total_count"].as_i64().unwrap_or_default();
+        result.incomplete_results |= response["incomplete_results"].as_bool().unwrap_or_default();
+
+        let items = response["items"].as_array().cloned().unwrap_or_default();
+        let received = items.len() as i64;
+        for item in items {
+            result.items.push(serde_json::from_value(item)?);
+        }
+
+        if received < per_page {
+            break;
+        }
+        page += 1;
+    }
+
+    result.items.truncate(limit as usize);
+    Ok(result)
+}
+
+// Search has own rate limit (30 requests per minute), code search has separate one
+// (10 requests per minute), so wait for reset of needed one when it is spent.
+// Octorust doesn't know `code_search` limit, so raw request is used
+async fn wait_for_rate_limit(path: &str) -> Result<(), Box<dyn Error>> {
+    let limits = rest::send(Method::GET, "/rate_limit", None).await?;
+    let limit = &limits["resources"][rate_limit_bucket(path)];
+    if limit["remaining"].as_i64().unwrap_or(1) > 0 {
+        return Ok(());
+    }
+
+    let reset = limit["reset"].as_i64().unwrap_or_default();
+    let wait = (reset - Utc::now().timestamp()).max(0) as u64 + 1;
+    eprintln!("Search rate limit is spent, waiting {wait} seconds");
+    tokio::time::sleep(Duration::from_secs(wait)).await;
+    Ok(())
+}
+
+fn rate_limit_bucket(path: &str) -> &'static str {
+    match path {
+        "/search/code" => "code_search",
+        _ => "search",
+    }
+}
+
+#[cfg(test)]
+mod search_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(&["bug"], &[("repo", "o/r"), ("label", "")], "bug repo:o/r")]
+    #[case(&["crash", "on start"], &[("is", "open")], "crash on start is:open")]
+    #[case(&[], &[("label", "good first issue"), ("-author", "bot")], "label:\"good first issue\" -author:bot")]
+    fn build_query_test(
+        #[case] words: &[&str],
+        #[case] qualifiers: &[(&str, &str)],
+        #[case] expected: &str,
+    ) {
+        let words: Vec<String> = words.iter().map(|w| w.to_string()).collect();
+        let qualifiers: Vec<(&str, String)> = qualifiers
+            .iter()
+            .map(|(k, v)| (*k, v.to_string()))
+            .collect();
+        assert_eq!(build_query(&words, &qualifiers), expected);
+    }
+
+    #[rstest]
+    #[case("/search/code", "code_search")]
+    #[case("/search/issues", "search")]
+    #[case("/search/users", "search")]
+    fn rate_limit_bucket_test(#[case] path: &str, #[case] expected: &str) {
+        assert_eq!(rate_limit_bucket(path), expected);
+    }
+}
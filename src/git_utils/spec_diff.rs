@@ -0,0 +1,96 @@
+use serde_json::Value;
+
+/// Field which differs in current and wanted state. Path is like `rules[0].type`
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub path: String,
+    pub old: Value,
+    pub new: Value,
+}
+
+// Only fields set in wanted state are compared, so fields github adds itself
+// (ids, urls, defaults) are not shown as changes
+pub fn diff(current: &Value, wanted: &Value) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    diff_in("", current, wanted, &mut changes);
+    changes
+}
+
+fn diff_in(path: &str, current: &Value, wanted: &Value, changes: &mut Vec<FieldChange>) {
+    match (current, wanted) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, new_value) in new {
+                let field_path = match path {
+                    "" => key.to_string(),
+                    _ => format!("{path}.{key}"),
+                };
+                diff_in(
+                    &field_path,
+                    old.get(key).unwrap_or(&Value::Null),
+                    new_value,
+                    changes,
+                );
+            }
+        }
+        (Value::Array(old), Value::Array(new)) if old.len() == new.len() => {
+            for (i, (old_item, new_item)) in old.iter().zip(new).enumerate() {
+                diff_in(&format!("{path}[{i}]"), old_item, new_item, changes);
+            }
+        }
+        _ => {
+            if current != wanted {
+                changes.push(FieldChange {
+                    path: path.to_string(),
+                    old: current.clone(),
+                    new: wanted.clone(),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod spec_diff_tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn diff_wanted_fields() {
+        let current = json!({
+            "id": 7,
+            "enforcement": "evaluate",
+            "rules": [{ "type": "deletion" }, { "type": "pull_request", "parameters": { "count": 1, "extra": true } }],
+        });
+        let wanted = json!({
+            "enforcement": "active",
+            "rules": [{ "type": "deletion" }, { "type": "pull_request", "parameters": { "count": 2 } }],
+        });
+
+        assert_eq!(
+            diff(&current, &wanted),
+            vec![
+                FieldChange {
+                    path: "enforcement".to_string(),
+                    old: json!("evaluate"),
+                    new: json!("active"),
+                },
+                FieldChange {
+                    path: "rules[1].parameters.count".to_string(),
+                    old: json!(1),
+                    new: json!(2),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_arrays_of_other_length() {
+        let changes = diff(
+            &json!({ "contexts": ["ci"] }),
+            &json!({ "contexts": ["ci", "lint"] }),
+        );
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "contexts");
+    }
+}
@@ -0,0 +1,119 @@
+use std::error::Error;
+
+use chrono::{Duration, Utc};
+use octorust::types::{IssueSimple, Order, Thread};
+use octorust::Client;
+use serde_json::json;
+
+use crate::cli_parse::entities::SearchArgs;
+use crate::git_utils::rest;
+use crate::git_utils::search;
+
+// Pull request of authenticated user with state of checks on last commit
+pub struct MyPull {
+    pub repo: String,
+    pub number: i64,
+    pub title: String,
+    pub draft: bool,
+    // SUCCESS, FAILURE, ERROR, PENDING, EXPECTED or empty if repo has no checks
+    pub checks: String,
+}
+
+pub struct Dashboard {
+    pub assigned: Vec<IssueSimple>,
+    pub review_requested: Vec<IssueSimple>,
+    pub my_pulls: Vec<MyPull>,
+    pub mentions: Vec<Thread>,
+}
+
+// Requests for all parts of dashboard are sent in parallel
+pub async fn get(github_client: &Client, limit: i64) -> Result<Dashboard, Box<dyn Error>> {
+    let (assigned, review_requested, my_pulls, mentions) = tokio::try_join!(
+        search_open("is:issue assignee:@me archived:false", limit),
+        search_open("is:pr review-requested:@me archived:false", limit),
+        get_my_pulls(limit),
+        get_mentions(github_client, limit),
+    )?;
+
+    Ok(Dashboard {
+        assigned,
+        review_requested,
+        my_pulls,
+        mentions,
+    })
+}
+
+async fn search_open(query: &str, limit: i64) -> Result<Vec<IssueSimple>, Box<dyn Error>> {
+    let args = SearchArgs {
+        query: format!("is:open {query}"),
+        sort: "updated".to_string(),
+        order: Order::Desc,
+        limit,
+    };
+
+    Ok(search::issues(&args).await?.items)
+}
+
+// Rest search doesn't give checks, so graphql is used to get them in one request
+async fn get_my_pulls(limit: i64) -> Result<Vec<MyPull>, Box<dyn Error>> {
+    let query = r#"
+        query($q: String!, $n: Int!) {
+            search(query: $q, type: ISSUE, first: $n) {
+                nodes {
+                    ... on PullRequest {
+                        number
+                        title
+                        isDraft
+                        repository { nameWithOwner }
+                        commits(last: 1) {
+                            nodes { commit { statusCheckRollup { state } } }
+                        }
+                    }
+                }
+            }
+        }"#;
+    let variables = json!({
+        "q": "is:open is:pr author:@me archived:false sort:updated-desc",
+        "n": limit,
+    });
+
+    let data = rest::graphql(query, variables).await?;
+    let nodes = data["search"]["nodes"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+
+    Ok(nodes
+        .iter()
+        .map(|pr| MyPull {
+            repo: pr["repository"]["nameWithOwner"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            number: pr["number"].as_i64().unwrap_or_default(),
+            title: pr["title"].as_str().unwrap_or_default().to_string(),
+            draft: pr["isDraft"].as_bool().unwrap_or_default(),
+            checks: pr["commits"]["nodes"][0]["commit"]["statusCheckRollup"]["state"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        })
+        .collect())
+}
+
+// Mentions of user and user teams for last week, read ones included
+async fn get_mentions(github_client: &Client, limit: i64) -> Result<Vec<Thread>, Box<dyn Error>> {
+    let since = Utc::now() - Duration::days(7);
+
+    let threads = github_client
+        .activity()
+        .list_notifications_for_authenticated_user(true, true, Some(since), None, 100, 1)
+        .await?
+        .body;
+
+    Ok(threads
+        .into_iter()
+        .filter(|t| t.reason == "mention" || t.reason == "team_mention")
+        .take(limit as usize)
+        .collect())
+}
@@ -1,6 +1,13 @@
+use std::collections::BTreeMap;
 use std::error::Error;
 
-use octorust::{types::FullTeam, Client};
+use octorust::{
+    types::{FullTeam, TeamsAddUpdateRepoPermissionsInOrgRequest},
+    Client,
+};
+use serde_json::json;
+
+use crate::git_utils::repo_info::RepoInfo;
 
 pub async fn get_id(
     github_client: &Client,
@@ -14,3 +21,71 @@ pub async fn get_id(
         Err(err) => Err(Box::new(err)),
     }
 }
+
+// Team slug and permission (`pull`, `triage`, `push`, `maintain` or `admin`) for teams with access to repo
+pub async fn get_repo_teams(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+) -> Result<BTreeMap<String, String>, Box<dyn Error>> {
+    let teams = github_client
+        .repos()
+        .list_all_teams(&repo_info.get_owner(), &repo_info.get_name())
+        .await;
+
+    match teams {
+        Ok(t) => Ok(t
+            .body
+            .into_iter()
+            .map(|team| (team.slug, team.permission))
+            .collect()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+pub async fn set_repo_permission(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    team_slug: &str,
+    permission: &str,
+) -> Result<String, Box<dyn Error>> {
+    let request = TeamsAddUpdateRepoPermissionsInOrgRequest {
+        permission: Some(serde_json::from_value(json!(permission))?),
+    };
+
+    let result = github_client
+        .teams()
+        .add_or_update_repo_permissions_in_org(
+            &repo_info.get_owner(),
+            team_slug,
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+            &request,
+        )
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
+
+pub async fn remove_repo(
+    github_client: &Client,
+    repo_info: &RepoInfo,
+    team_slug: &str,
+) -> Result<String, Box<dyn Error>> {
+    let result = github_client
+        .teams()
+        .remove_repo_in_org(
+            &repo_info.get_owner(),
+            team_slug,
+            &repo_info.get_owner(),
+            &repo_info.get_name(),
+        )
+        .await;
+
+    match result {
+        Ok(_) => Ok("Success".to_string()),
+        Err(er) => Err(Box::new(er)),
+    }
+}
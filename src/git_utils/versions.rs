@@ -0,0 +1,84 @@
+use semver::{Prerelease, Version};
+
+use crate::cli_in::set_vars::BumpLevel;
+
+// Split tag like `v1.2.3` to prefix `v` and version. Tags without prefix are allowed too
+pub fn parse_tag(tag: &str) -> Result<(String, Version), semver::Error> {
+    let (prefix, version) = match tag.strip_prefix('v') {
+        Some(v) => ("v", v),
+        None => ("", tag),
+    };
+
+    Ok((prefix.to_string(), Version::parse(version)?))
+}
+
+// Next version. Prerelease of release is `<next patch>-<preid>.1`,
+// prerelease of prerelease increments its last number: `1.0.0-rc.1` -> `1.0.0-rc.2`
+pub fn bump(version: &Version, level: &BumpLevel, preid: &str) -> Result<Version, semver::Error> {
+    let mut next = version.clone();
+    next.build = semver::BuildMetadata::EMPTY;
+
+    match level {
+        BumpLevel::Major => {
+            next = Version::new(version.major + 1, 0, 0);
+        }
+        BumpLevel::Minor => {
+            next = Version::new(version.major, version.minor + 1, 0);
+        }
+        // Release of prerelease version is the same version without prerelease part
+        BumpLevel::Patch => {
+            if version.pre.is_empty() {
+                next.patch += 1;
+            }
+            next.pre = Prerelease::EMPTY;
+        }
+        BumpLevel::Prerelease => {
+            if version.pre.is_empty() {
+                next.patch += 1;
+                next.pre = Prerelease::new(&format!("{preid}.1"))?;
+            } else {
+                next.pre = Prerelease::new(&next_prerelease(version.pre.as_str()))?;
+            }
+        }
+    }
+    Ok(next)
+}
+
+fn next_prerelease(pre: &str) -> String {
+    if let Some((head, number)) = pre.rsplit_once('.') {
+        if let Ok(n) = number.parse::<u64>() {
+            return format!("{}.{}", head, n + 1);
+        }
+    }
+    format!("{pre}.1")
+}
+
+#[cfg(test)]
+mod versions_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("v1.2.3", "v", "1.2.3")]
+    #[case("0.4.0-rc.1", "", "0.4.0-rc.1")]
+    fn parse_tag_test(#[case] tag: &str, #[case] prefix: &str, #[case] version: &str) {
+        let (p, v) = parse_tag(tag).unwrap();
+
+        assert_eq!(p, prefix);
+        assert_eq!(v.to_string(), version);
+    }
+
+    #[rstest]
+    #[case("1.2.3", BumpLevel::Major, "2.0.0")]
+    #[case("1.2.3", BumpLevel::Minor, "1.3.0")]
+    #[case("1.2.3", BumpLevel::Patch, "1.2.4")]
+    #[case("1.2.3-rc.2", BumpLevel::Patch, "1.2.3")]
+    #[case("1.2.3", BumpLevel::Prerelease, "1.2.4-rc.1")]
+    #[case("1.2.4-rc.1", BumpLevel::Prerelease, "1.2.4-rc.2")]
+    #[case("1.2.4-beta", BumpLevel::Prerelease, "1.2.4-beta.1")]
+    fn bump_test(#[case] version: &str, #[case] level: BumpLevel, #[case] expected: &str) {
+        let next = bump(&Version::parse(version).unwrap(), &level, "rc").unwrap();
+
+        assert_eq!(next.to_string(), expected);
+    }
+}
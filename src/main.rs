@@ -6,6 +6,7 @@ mod cli_in;
 mod cli_out;
 mod cli_parse;
 mod git_utils;
+mod tui;
 
 use crate::{cli_in::read_cli::Args, cli_parse::handle_cli::handle_cli_command};
 
@@ -0,0 +1,255 @@
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Span;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    Hunk,
+    Added,
+    Removed,
+    Context,
+}
+
+/// One line of file patch with its numbers in old and new file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub text: String,
+    pub old_line: Option<i64>,
+    pub new_line: Option<i64>,
+}
+
+/// Parse start lines from hunk header like `@@ -10,7 +12,8 @@ fn main()`
+fn parse_hunk_header(header: &str) -> Option<(i64, i64)> {
+    let mut parts = header.split_whitespace().skip(1);
+    let old_start = parts.next()?.trim_start_matches('-').split(',').next()?;
+    let new_start = parts.next()?.trim_start_matches('+').split(',').next()?;
+    Some((old_start.parse().ok()?, new_start.parse().ok()?))
+}
+
+/// Split patch from github into lines with line numbers
+pub fn parse_patch(patch: &str) -> Vec<DiffLine> {
+    let mut old_line = 0;
+    let mut new_line = 0;
+    let mut lines = Vec::new();
+
+    for line in patch.lines() {
+        if line.starts_with("@@") {
+            if let Some((old_start, new_start)) = parse_hunk_header(line) {
+                old_line = old_start;
+                new_line = new_start;
+            }
+            lines.push(DiffLine {
+                kind: DiffLineKind::Hunk,
+                text: line.to_string(),
+                old_line: None,
+                new_line: None,
+            });
+        } else if let Some(text) = line.strip_prefix('+') {
+            lines.push(DiffLine {
+                kind: DiffLineKind::Added,
+                text: text.to_string(),
+                old_line: None,
+                new_line: Some(new_line),
+            });
+            new_line += 1;
+        } else if let Some(text) = line.strip_prefix('-') {
+            lines.push(DiffLine {
+                kind: DiffLineKind::Removed,
+                text: text.to_string(),
+                old_line: Some(old_line),
+                new_line: None,
+            });
+            old_line += 1;
+        } else if line.starts_with('\\') {
+            // "\ No newline at end of file"
+            continue;
+        } else {
+            lines.push(DiffLine {
+                kind: DiffLineKind::Context,
+                text: line.strip_prefix(' ').unwrap_or(line).to_string(),
+                old_line: Some(old_line),
+                new_line: Some(new_line),
+            });
+            old_line += 1;
+            new_line += 1;
+        }
+    }
+    lines
+}
+
+/// Keywords of language detected by file extension
+fn keywords(filename: &str) -> &'static [&'static str] {
+    let extension = filename.rsplit('.').next().unwrap_or("");
+    match extension {
+        "rs" => &[
+            "as", "async", "await", "break", "const", "continue", "crate", "else", "enum", "fn",
+            "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+            "return", "self", "Self", "static", "struct", "super", "trait", "type", "use", "where",
+            "while",
+        ],
+        "py" => &[
+            "and", "as", "async", "await", "class", "def", "elif", "else", "except", "for", "from",
+            "if", "import", "in", "is", "lambda", "not", "or", "pass", "raise", "return", "try",
+            "while", "with", "yield",
+        ],
+        "js" | "jsx" | "ts" | "tsx" => &[
+            "async",
+            "await",
+            "break",
+            "class",
+            "const",
+            "else",
+            "export",
+            "extends",
+            "for",
+            "function",
+            "if",
+            "import",
+            "interface",
+            "let",
+            "new",
+            "return",
+            "switch",
+            "this",
+            "throw",
+            "try",
+            "type",
+            "var",
+            "while",
+        ],
+        "go" => &[
+            "break",
+            "case",
+            "chan",
+            "const",
+            "continue",
+            "defer",
+            "else",
+            "for",
+            "func",
+            "go",
+            "if",
+            "import",
+            "interface",
+            "map",
+            "package",
+            "range",
+            "return",
+            "select",
+            "struct",
+            "switch",
+            "type",
+            "var",
+        ],
+        "c" | "h" | "cpp" | "hpp" | "cc" | "java" | "kt" | "cs" => &[
+            "break", "case", "class", "const", "continue", "else", "enum", "for", "if", "new",
+            "private", "public", "return", "static", "struct", "switch", "this", "void", "while",
+        ],
+        _ => &[],
+    }
+}
+
+/// Line comment start of language detected by file extension
+fn comment_start(filename: &str) -> Option<&'static str> {
+    let extension = filename.rsplit('.').next().unwrap_or("");
+    match extension {
+        "py" | "sh" | "toml" | "yml" | "yaml" | "rb" => Some("#"),
+        "rs" | "js" | "jsx" | "ts" | "tsx" | "go" | "c" | "h" | "cpp" | "hpp" | "cc" | "java"
+        | "kt" | "cs" => Some("//"),
+        _ => None,
+    }
+}
+
+/// Colour code line: keywords, strings and line comments
+pub fn highlight(code: &str, filename: &str, base: Style) -> Vec<Span<'static>> {
+    let keyword_style = base.fg(Color::Magenta).add_modifier(Modifier::BOLD);
+    let string_style = base.fg(Color::Yellow);
+    let comment_style = base.fg(Color::DarkGray);
+
+    let (code, comment) = match comment_start(filename).and_then(|c| code.find(c)) {
+        Some(index) => (&code[..index], Some(&code[index..])),
+        None => (code, None),
+    };
+
+    let keywords = keywords(filename);
+    let mut spans = Vec::new();
+    let mut word = String::new();
+    let mut in_string: Option<char> = None;
+    let mut string = String::new();
+
+    let flush_word = |word: &mut String, spans: &mut Vec<Span<'static>>| {
+        if word.is_empty() {
+            return;
+        }
+        let style = if keywords.contains(&word.as_str()) {
+            keyword_style
+        } else {
+            base
+        };
+        spans.push(Span::styled(std::mem::take(word), style));
+    };
+
+    for c in code.chars() {
+        if let Some(quote) = in_string {
+            string.push(c);
+            if c == quote {
+                spans.push(Span::styled(std::mem::take(&mut string), string_style));
+                in_string = None;
+            }
+        } else if c == '"' || c == '\'' || c == '`' {
+            flush_word(&mut word, &mut spans);
+            in_string = Some(c);
+            string.push(c);
+        } else if c.is_alphanumeric() || c == '_' {
+            word.push(c);
+        } else {
+            flush_word(&mut word, &mut spans);
+            spans.push(Span::styled(c.to_string(), base));
+        }
+    }
+    flush_word(&mut word, &mut spans);
+    if !string.is_empty() {
+        spans.push(Span::styled(string, string_style));
+    }
+    if let Some(comment) = comment {
+        spans.push(Span::styled(comment.to_string(), comment_style));
+    }
+    spans
+}
+
+#[cfg(test)]
+mod diff_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("@@ -10,7 +12,8 @@ fn main()", Some((10, 12)))]
+    #[case("@@ -1 +1 @@", Some((1, 1)))]
+    #[case("@@ -0,0 +1,3 @@", Some((0, 1)))]
+    #[case("@@ broken @@", None)]
+    fn hunk_header(#[case] header: &str, #[case] expected: Option<(i64, i64)>) {
+        assert_eq!(parse_hunk_header(header), expected);
+    }
+
+    #[test]
+    fn patch_line_numbers() {
+        let patch = "@@ -3,3 +3,3 @@\n fn a() {\n-    old();\n+    new();\n }";
+        let lines = parse_patch(patch);
+
+        let numbers: Vec<(DiffLineKind, Option<i64>, Option<i64>)> = lines
+            .iter()
+            .map(|l| (l.kind, l.old_line, l.new_line))
+            .collect();
+        assert_eq!(
+            numbers,
+            vec![
+                (DiffLineKind::Hunk, None, None),
+                (DiffLineKind::Context, Some(3), Some(3)),
+                (DiffLineKind::Removed, Some(4), None),
+                (DiffLineKind::Added, None, Some(4)),
+                (DiffLineKind::Context, Some(5), Some(5)),
+            ]
+        );
+        assert_eq!(lines[2].text, "    old();");
+    }
+}
@@ -0,0 +1,436 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use octorust::types::{IssueComment, IssueSimple, State};
+use octorust::Client;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::cli_parse::entities::ListIssueArgs;
+use crate::git_utils::repo_info::RepoInfo;
+use crate::git_utils::{comments, issues};
+use crate::tui::{edit_in_editor, split_list};
+
+const HELP: &str = "j/k move  / filter  c close  o reopen  l label  a assign  m comment  n/p page  J/K scroll  r reload  q quit";
+
+/// What user is typing in status bar
+enum InputKind {
+    Filter,
+    Labels,
+    Assignees,
+}
+
+struct Input {
+    kind: InputKind,
+    text: String,
+}
+
+struct IssuesTui {
+    repo_info: RepoInfo,
+    list_args: ListIssueArgs,
+    issues: Vec<IssueSimple>,
+    comments: HashMap<i64, Vec<IssueComment>>,
+    filter: String,
+    list_state: ListState,
+    input: Option<Input>,
+    scroll: u16,
+    status: String,
+    exit: bool,
+}
+
+/// Run issues tui until user quit
+pub async fn run(
+    github_client: &Client,
+    repo_info: RepoInfo,
+    list_args: ListIssueArgs,
+) -> Result<(), Box<dyn Error>> {
+    let mut app = IssuesTui {
+        repo_info,
+        list_args,
+        issues: Vec::new(),
+        comments: HashMap::new(),
+        filter: String::new(),
+        list_state: ListState::default(),
+        input: None,
+        scroll: 0,
+        status: HELP.to_string(),
+        exit: false,
+    };
+    // Load first page before tui start, so errors are printed as usual
+    app.issues = app.fetch_issues(github_client).await?;
+    app.list_state.select(Some(0));
+
+    let mut terminal = ratatui::init();
+    let result = app.run(github_client, &mut terminal).await;
+    ratatui::restore();
+
+    result
+}
+
+impl IssuesTui {
+    async fn run(
+        &mut self,
+        github_client: &Client,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<(), Box<dyn Error>> {
+        while !self.exit {
+            self.load_comments(github_client).await;
+            terminal.draw(|frame| self.draw(frame))?;
+
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    self.handle_key(key, github_client, terminal).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn fetch_issues(
+        &self,
+        github_client: &Client,
+    ) -> Result<Vec<IssueSimple>, Box<dyn Error>> {
+        let list_issues = issues::get_list(github_client, &self.repo_info, &self.list_args).await?;
+
+        Ok(list_issues
+            .into_iter()
+            .filter(|iss| iss.pull_request.is_none())
+            .collect())
+    }
+
+    async fn reload(&mut self, github_client: &Client) {
+        match self.fetch_issues(github_client).await {
+            Ok(list_issues) => {
+                self.issues = list_issues;
+                self.comments.clear();
+                self.select(0);
+            }
+            Err(er) => self.status = format!("Error: {er}"),
+        }
+    }
+
+    /// Load comments of selected issue, if they were not loaded before
+    async fn load_comments(&mut self, github_client: &Client) {
+        let number = match self.selected() {
+            Some(iss) => iss.number,
+            None => return,
+        };
+        if self.comments.contains_key(&number) {
+            return;
+        }
+
+        match comments::get_all(github_client, &self.repo_info, &number).await {
+            Ok(list_comments) => {
+                self.comments.insert(number, list_comments);
+            }
+            Err(er) => {
+                self.comments.insert(number, Vec::new());
+                self.status = format!("Error: {er}");
+            }
+        }
+    }
+
+    /// Issues which contain filter in number or title
+    fn visible(&self) -> Vec<&IssueSimple> {
+        let filter = self.filter.to_lowercase();
+        self.issues
+            .iter()
+            .filter(|iss| {
+                filter.is_empty()
+                    || iss.title.to_lowercase().contains(&filter)
+                    || iss.number.to_string().contains(&filter)
+            })
+            .collect()
+    }
+
+    fn selected(&self) -> Option<&IssueSimple> {
+        let index = self.list_state.selected()?;
+        self.visible().get(index).copied()
+    }
+
+    fn select(&mut self, index: usize) {
+        let len = self.visible().len();
+        self.list_state.select(if len == 0 {
+            None
+        } else {
+            Some(index.min(len - 1))
+        });
+        self.scroll = 0;
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main_area, status_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [list_area, issue_area] =
+            Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
+                .areas(main_area);
+
+        let items: Vec<ListItem> = self
+            .visible()
+            .iter()
+            .map(|iss| ListItem::new(format!("#{} {}", iss.number, iss.title)))
+            .collect();
+        let list_title = format!(
+            " {} issues from {} page {} ",
+            self.list_args.state,
+            self.list_args.numb_of_page,
+            self.filter_title()
+        );
+        let list = List::new(items)
+            .block(Block::bordered().title(list_title))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(list, list_area, &mut self.list_state);
+
+        let issue = Paragraph::new(self.issue_text())
+            .block(Block::bordered().title(" Issue "))
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll, 0));
+        frame.render_widget(issue, issue_area);
+
+        let status = match &self.input {
+            Some(input) => {
+                let prompt = match input.kind {
+                    InputKind::Filter => "Filter",
+                    InputKind::Labels => "Add labels (comma separated)",
+                    InputKind::Assignees => "Add assignees (comma separated)",
+                };
+                format!("{prompt}: {}", input.text)
+            }
+            None => self.status.clone(),
+        };
+        frame.render_widget(Paragraph::new(status), status_area);
+    }
+
+    fn filter_title(&self) -> String {
+        if self.filter.is_empty() {
+            String::new()
+        } else {
+            format!("[{}]", self.filter)
+        }
+    }
+
+    /// Rendered body and comments of selected issue
+    fn issue_text(&self) -> Text<'static> {
+        let issue = match self.selected() {
+            Some(iss) => iss,
+            None => return Text::from("Issue not find"),
+        };
+        let bold = Style::new().add_modifier(Modifier::BOLD);
+
+        let labels: Vec<&str> = issue.labels.iter().map(|l| l.name.as_str()).collect();
+        let assignees: Vec<&str> = issue.assignees.iter().map(|a| a.login.as_str()).collect();
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!("#{} {}", issue.number, issue.title),
+                bold,
+            )),
+            Line::from(format!("State: {}", issue.state)),
+            Line::from(format!("Labels: {}", labels.join(", "))),
+            Line::from(format!("Assignees: {}", assignees.join(", "))),
+            Line::from(""),
+        ];
+        lines.extend(issue.body.lines().map(|l| Line::from(l.to_string())));
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("Comments:", bold)));
+        match self.comments.get(&issue.number) {
+            Some(list_comments) => {
+                for comment in list_comments {
+                    let who = match &comment.user {
+                        Some(u) => u.login.clone(),
+                        None => String::new(),
+                    };
+                    lines.push(Line::from(""));
+                    lines.push(Line::from(Span::styled(who, bold)));
+                    lines.extend(comment.body.lines().map(|l| Line::from(l.to_string())));
+                }
+            }
+            None => lines.push(Line::from("Loading...")),
+        }
+
+        Text::from(lines)
+    }
+
+    async fn handle_key(
+        &mut self,
+        key: KeyEvent,
+        github_client: &Client,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<(), Box<dyn Error>> {
+        if self.input.is_some() {
+            self.handle_input_key(key, github_client).await;
+            return Ok(());
+        }
+
+        let index = self.list_state.selected().unwrap_or(0);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.exit = true,
+            KeyCode::Char('j') | KeyCode::Down => self.select(index + 1),
+            KeyCode::Char('k') | KeyCode::Up => self.select(index.saturating_sub(1)),
+            KeyCode::Char('J') | KeyCode::PageDown => self.scroll = self.scroll.saturating_add(5),
+            KeyCode::Char('K') | KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(5),
+            KeyCode::Char('/') => {
+                self.input = Some(Input {
+                    kind: InputKind::Filter,
+                    text: self.filter.clone(),
+                })
+            }
+            KeyCode::Char('l') => {
+                self.input = Some(Input {
+                    kind: InputKind::Labels,
+                    text: String::new(),
+                })
+            }
+            KeyCode::Char('a') => {
+                self.input = Some(Input {
+                    kind: InputKind::Assignees,
+                    text: String::new(),
+                })
+            }
+            KeyCode::Char('c') => self.set_state(github_client, State::Closed).await,
+            KeyCode::Char('o') => self.set_state(github_client, State::Open).await,
+            KeyCode::Char('m') => self.comment(github_client, terminal).await?,
+            KeyCode::Char('n') | KeyCode::Right => {
+                self.list_args.numb_of_page += 1;
+                self.reload(github_client).await;
+            }
+            KeyCode::Char('p') | KeyCode::Left if self.list_args.numb_of_page > 1 => {
+                self.list_args.numb_of_page -= 1;
+                self.reload(github_client).await;
+            }
+            KeyCode::Char('r') => self.reload(github_client).await,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn handle_input_key(&mut self, key: KeyEvent, github_client: &Client) {
+        let input = match self.input.as_mut() {
+            Some(i) => i,
+            None => return,
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                if let InputKind::Filter = input.kind {
+                    self.filter.clear();
+                    self.select(0);
+                }
+                self.input = None;
+            }
+            KeyCode::Backspace => {
+                input.text.pop();
+                if let InputKind::Filter = input.kind {
+                    self.filter = input.text.clone();
+                    self.select(0);
+                }
+            }
+            KeyCode::Char(c) => {
+                input.text.push(c);
+                if let InputKind::Filter = input.kind {
+                    self.filter = input.text.clone();
+                    self.select(0);
+                }
+            }
+            KeyCode::Enter => {
+                if let Some(input) = self.input.take() {
+                    match input.kind {
+                        InputKind::Filter => {}
+                        InputKind::Labels => {
+                            self.add_labels(github_client, split_list(&input.text))
+                                .await
+                        }
+                        InputKind::Assignees => {
+                            self.add_assignees(github_client, split_list(&input.text))
+                                .await
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    async fn set_state(&mut self, github_client: &Client, state: State) {
+        let number = match self.selected() {
+            Some(iss) => iss.number,
+            None => return,
+        };
+
+        match issues::set_state(github_client, &self.repo_info, number, state.clone()).await {
+            Ok(_) => {
+                self.reload(github_client).await;
+                self.status = format!("Issue #{number} is {state} now");
+            }
+            Err(er) => self.status = format!("Error: {er}"),
+        }
+    }
+
+    async fn add_labels(&mut self, github_client: &Client, labels: Vec<String>) {
+        let number = match self.selected() {
+            Some(iss) => iss.number,
+            None => return,
+        };
+        if labels.is_empty() {
+            return;
+        }
+
+        match issues::add_labels(github_client, &self.repo_info, number, &labels).await {
+            Ok(_) => {
+                self.reload(github_client).await;
+                self.status = format!("Labels added to #{number}");
+            }
+            Err(er) => self.status = format!("Error: {er}"),
+        }
+    }
+
+    async fn add_assignees(&mut self, github_client: &Client, assignees: Vec<String>) {
+        let number = match self.selected() {
+            Some(iss) => iss.number,
+            None => return,
+        };
+        if assignees.is_empty() {
+            return;
+        }
+
+        match issues::add_assignees(github_client, &self.repo_info, number, &assignees).await {
+            Ok(_) => {
+                self.reload(github_client).await;
+                self.status = format!("Assignees added to #{number}");
+            }
+            Err(er) => self.status = format!("Error: {er}"),
+        }
+    }
+
+    async fn comment(
+        &mut self,
+        github_client: &Client,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<(), Box<dyn Error>> {
+        let number = match self.selected() {
+            Some(iss) => iss.number,
+            None => return Ok(()),
+        };
+
+        let body = edit_in_editor(terminal)?;
+        if body.trim().is_empty() {
+            self.status = "Empty comment, nothing to send".to_string();
+            return Ok(());
+        }
+
+        match comments::create(github_client, &self.repo_info, &number, body.trim()).await {
+            Ok(result) => {
+                self.comments.remove(&number);
+                self.status = result;
+            }
+            Err(er) => self.status = format!("Error: {er}"),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+use std::process::Command;
+use std::{env, fs, io, process};
+
+use ratatui::DefaultTerminal;
+
+pub mod diff;
+pub mod issues;
+pub mod prs;
+pub mod repos;
+
+/// Temp file for editor, removed when editing ends (on errors too)
+struct EditFile(PathBuf);
+
+impl Drop for EditFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+/// Suspend tui, open $EDITOR (or vi) with empty temp file and return written text
+pub fn edit_in_editor(terminal: &mut DefaultTerminal) -> Result<String, io::Error> {
+    let file = EditFile(env::temp_dir().join(format!("github-cli-edit-{}.md", process::id())));
+    let path = &file.0;
+    fs::write(path, "")?;
+
+    let editor = env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+    // $EDITOR can contain args, like `code --wait`
+    let mut editor_parts = editor.split_whitespace();
+    let program = editor_parts.next().unwrap_or("vi");
+
+    ratatui::restore();
+    let status = Command::new(program).args(editor_parts).arg(path).status();
+    *terminal = ratatui::init();
+
+    let status = status?;
+    if !status.success() {
+        return Err(io::Error::other(format!("{editor} exited with {status}")));
+    }
+
+    fs::read_to_string(path)
+}
+
+/// Split comma separated input into trimmed non-empty values
+pub fn split_list(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
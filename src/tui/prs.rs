@@ -0,0 +1,533 @@
+use std::error::Error;
+
+use octorust::types::{
+    Comments, DiffEntry, IssuesListState, Order, PullRequestReviewComment, PullRequestSimple,
+    PullsCreateReviewRequestEvent, Side, Sort,
+};
+use octorust::Client;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::git_utils::repo_info::RepoInfo;
+use crate::git_utils::{comments, pulls};
+use crate::tui::diff::{highlight, parse_patch, DiffLine, DiffLineKind};
+use crate::tui::edit_in_editor;
+
+const LIST_HELP: &str = "j/k move  Enter review  n/p page  r reload  q quit";
+const REVIEW_HELP: &str =
+    "j/k line  h/l file  c comment line  d drop pending  s submit review  Esc back";
+const SUBMIT_HELP: &str = "Submit review: a approve  x request changes  c comment  Esc cancel";
+
+enum Screen {
+    List,
+    Review,
+    Submit,
+}
+
+/// Pull request opened for review
+struct Review {
+    number: i64,
+    files: Vec<DiffEntry>,
+    file_state: ListState,
+    diff: Vec<DiffLine>,
+    cursor: usize,
+    comments: Vec<PullRequestReviewComment>,
+    pending: Vec<Comments>,
+}
+
+struct PrsTui {
+    repo_info: RepoInfo,
+    state: IssuesListState,
+    numb_of_page: i64,
+    prs_on_page: i64,
+    prs: Vec<PullRequestSimple>,
+    list_state: ListState,
+    review: Option<Review>,
+    screen: Screen,
+    status: String,
+    exit: bool,
+}
+
+/// Run pull requests tui until user quit
+pub async fn run(
+    github_client: &Client,
+    repo_info: RepoInfo,
+    state: IssuesListState,
+    prs_on_page: i64,
+) -> Result<(), Box<dyn Error>> {
+    let mut app = PrsTui {
+        repo_info,
+        state,
+        numb_of_page: 1,
+        prs_on_page,
+        prs: Vec::new(),
+        list_state: ListState::default(),
+        review: None,
+        screen: Screen::List,
+        status: LIST_HELP.to_string(),
+        exit: false,
+    };
+    // Load first page before tui start, so errors are printed as usual
+    app.prs = app.fetch_prs(github_client).await?;
+    app.list_state.select(Some(0));
+
+    let mut terminal = ratatui::init();
+    let result = app.run(github_client, &mut terminal).await;
+    ratatui::restore();
+
+    result
+}
+
+/// Place of diff line for inline comment: line number and side
+fn comment_place(line: &DiffLine) -> Option<(i64, Side)> {
+    match line.kind {
+        DiffLineKind::Hunk => None,
+        DiffLineKind::Removed => Some((line.old_line?, Side::Left)),
+        DiffLineKind::Added | DiffLineKind::Context => Some((line.new_line?, Side::Right)),
+    }
+}
+
+impl PrsTui {
+    async fn run(
+        &mut self,
+        github_client: &Client,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<(), Box<dyn Error>> {
+        while !self.exit {
+            terminal.draw(|frame| self.draw(frame))?;
+
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    self.handle_key(key, github_client, terminal).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn fetch_prs(
+        &self,
+        github_client: &Client,
+    ) -> Result<Vec<PullRequestSimple>, Box<dyn Error>> {
+        pulls::get_list(
+            github_client,
+            &self.repo_info,
+            self.state.clone(),
+            self.numb_of_page,
+            self.prs_on_page,
+        )
+        .await
+    }
+
+    async fn reload(&mut self, github_client: &Client) {
+        match self.fetch_prs(github_client).await {
+            Ok(list_prs) => {
+                self.prs = list_prs;
+                self.list_state
+                    .select(if self.prs.is_empty() { None } else { Some(0) });
+            }
+            Err(er) => self.status = format!("Error: {er}"),
+        }
+    }
+
+    /// Load files and review comments of selected pull request
+    async fn open_review(&mut self, github_client: &Client) {
+        let number = match self.list_state.selected().and_then(|i| self.prs.get(i)) {
+            Some(pr) => pr.number,
+            None => return,
+        };
+
+        let files = match pulls::get_files(github_client, &self.repo_info, number).await {
+            Ok(f) => f,
+            Err(er) => {
+                self.status = format!("Error: {er}");
+                return;
+            }
+        };
+        let review_comments = match comments::get_all_from_review(
+            github_client,
+            &self.repo_info,
+            &number,
+            Sort::Created,
+            Order::Asc,
+        )
+        .await
+        {
+            Ok(c) => c,
+            Err(er) => {
+                self.status = format!("Error: {er}");
+                return;
+            }
+        };
+
+        let mut review = Review {
+            number,
+            files,
+            file_state: ListState::default(),
+            diff: Vec::new(),
+            cursor: 0,
+            comments: review_comments,
+            pending: Vec::new(),
+        };
+        review.select_file(0);
+
+        self.review = Some(review);
+        self.screen = Screen::Review;
+        self.status = REVIEW_HELP.to_string();
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main_area, status_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+
+        match (&self.screen, self.review.as_mut()) {
+            (Screen::List, _) | (_, None) => {
+                let [list_area, pr_area] =
+                    Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
+                        .areas(main_area);
+
+                let items: Vec<ListItem> = self
+                    .prs
+                    .iter()
+                    .map(|pr| {
+                        let draft = if pr.draft { " [draft]" } else { "" };
+                        ListItem::new(format!("#{} {}{draft}", pr.number, pr.title))
+                    })
+                    .collect();
+                let list = List::new(items)
+                    .block(Block::bordered().title(format!(
+                        " {} pull requests from {} page ",
+                        self.state, self.numb_of_page
+                    )))
+                    .highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+                    .highlight_symbol("> ");
+                frame.render_stateful_widget(list, list_area, &mut self.list_state);
+
+                let pr_text = match self.list_state.selected().and_then(|i| self.prs.get(i)) {
+                    Some(pr) => {
+                        let mut lines = vec![
+                            Line::from(Span::styled(
+                                format!("#{} {}", pr.number, pr.title),
+                                Style::new().add_modifier(Modifier::BOLD),
+                            )),
+                            Line::from(format!("{} <- {}", pr.base.ref_, pr.head.ref_)),
+                            Line::from(""),
+                        ];
+                        lines.extend(pr.body.lines().map(|l| Line::from(l.to_string())));
+                        Text::from(lines)
+                    }
+                    None => Text::from("Pull request not find"),
+                };
+                let pr_widget = Paragraph::new(pr_text)
+                    .block(Block::bordered().title(" Pull request "))
+                    .wrap(Wrap { trim: false });
+                frame.render_widget(pr_widget, pr_area);
+            }
+            (Screen::Review | Screen::Submit, Some(review)) => {
+                let [files_area, diff_area] =
+                    Layout::horizontal([Constraint::Percentage(25), Constraint::Percentage(75)])
+                        .areas(main_area);
+
+                let items: Vec<ListItem> = review
+                    .files
+                    .iter()
+                    .map(|f| {
+                        ListItem::new(format!("{} +{} -{}", f.filename, f.additions, f.deletions))
+                    })
+                    .collect();
+                let list = List::new(items)
+                    .block(Block::bordered().title(format!(
+                        " #{} files ({} pending comments) ",
+                        review.number,
+                        review.pending.len()
+                    )))
+                    .highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+                    .highlight_symbol("> ");
+                frame.render_stateful_widget(list, files_area, &mut review.file_state);
+
+                let (diff_text, cursor_row) = review.diff_text();
+                // Keep cursor line in the middle of diff pane
+                let scroll = cursor_row.saturating_sub(diff_area.height as usize / 2);
+                let diff_widget = Paragraph::new(diff_text)
+                    .block(Block::bordered().title(" Diff "))
+                    .scroll((scroll as u16, 0));
+                frame.render_widget(diff_widget, diff_area);
+            }
+        }
+
+        frame.render_widget(Paragraph::new(self.status.clone()), status_area);
+    }
+
+    async fn handle_key(
+        &mut self,
+        key: KeyEvent,
+        github_client: &Client,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<(), Box<dyn Error>> {
+        match self.screen {
+            Screen::List => self.handle_list_key(key, github_client).await,
+            Screen::Review => self.handle_review_key(key, terminal)?,
+            Screen::Submit => self.handle_submit_key(key, github_client, terminal).await?,
+        }
+        Ok(())
+    }
+
+    async fn handle_list_key(&mut self, key: KeyEvent, github_client: &Client) {
+        let index = self.list_state.selected().unwrap_or(0);
+        let last = self.prs.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.exit = true,
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.list_state.select(Some((index + 1).min(last)))
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.list_state.select(Some(index.saturating_sub(1)))
+            }
+            KeyCode::Enter => self.open_review(github_client).await,
+            KeyCode::Char('n') | KeyCode::Right => {
+                self.numb_of_page += 1;
+                self.reload(github_client).await;
+            }
+            KeyCode::Char('p') | KeyCode::Left if self.numb_of_page > 1 => {
+                self.numb_of_page -= 1;
+                self.reload(github_client).await;
+            }
+            KeyCode::Char('r') => self.reload(github_client).await,
+            _ => {}
+        }
+    }
+
+    fn handle_review_key(
+        &mut self,
+        key: KeyEvent,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<(), Box<dyn Error>> {
+        let review = match self.review.as_mut() {
+            Some(r) => r,
+            None => return Ok(()),
+        };
+
+        let file_index = review.file_state.selected().unwrap_or(0);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                if !review.pending.is_empty() {
+                    self.status = format!(
+                        "{} pending comments will be lost. Submit them with 's' or drop with 'd'",
+                        review.pending.len()
+                    );
+                    return Ok(());
+                }
+                self.review = None;
+                self.screen = Screen::List;
+                self.status = LIST_HELP.to_string();
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                review.cursor = (review.cursor + 1).min(review.diff.len().saturating_sub(1))
+            }
+            KeyCode::Char('k') | KeyCode::Up => review.cursor = review.cursor.saturating_sub(1),
+            KeyCode::PageDown => {
+                review.cursor = (review.cursor + 20).min(review.diff.len().saturating_sub(1))
+            }
+            KeyCode::PageUp => review.cursor = review.cursor.saturating_sub(20),
+            KeyCode::Char('l') | KeyCode::Right | KeyCode::Tab => {
+                review.select_file(file_index + 1)
+            }
+            KeyCode::Char('h') | KeyCode::Left | KeyCode::BackTab => {
+                review.select_file(file_index.saturating_sub(1))
+            }
+            KeyCode::Char('c') => {
+                let place = review.diff.get(review.cursor).and_then(comment_place);
+                let path = review
+                    .files
+                    .get(file_index)
+                    .map(|f| f.filename.clone())
+                    .unwrap_or_default();
+                match place {
+                    Some((line, side)) => {
+                        let body = edit_in_editor(terminal)?;
+                        if body.trim().is_empty() {
+                            self.status = "Empty comment, nothing to add".to_string();
+                        } else {
+                            review.pending.push(Comments {
+                                body: body.trim().to_string(),
+                                line,
+                                path,
+                                position: 0,
+                                side: side.to_string(),
+                                start_line: 0,
+                                start_side: String::new(),
+                            });
+                            self.status = REVIEW_HELP.to_string();
+                        }
+                    }
+                    None => self.status = "Can't comment hunk header".to_string(),
+                }
+            }
+            KeyCode::Char('d') => {
+                review.pending.clear();
+                self.status = "Pending comments dropped".to_string();
+            }
+            KeyCode::Char('s') => {
+                self.screen = Screen::Submit;
+                self.status = SUBMIT_HELP.to_string();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn handle_submit_key(
+        &mut self,
+        key: KeyEvent,
+        github_client: &Client,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<(), Box<dyn Error>> {
+        let event = match key.code {
+            KeyCode::Char('a') => PullsCreateReviewRequestEvent::Approve,
+            KeyCode::Char('x') => PullsCreateReviewRequestEvent::RequestChanges,
+            KeyCode::Char('c') => PullsCreateReviewRequestEvent::Comment,
+            KeyCode::Esc => {
+                self.screen = Screen::Review;
+                self.status = REVIEW_HELP.to_string();
+                return Ok(());
+            }
+            _ => return Ok(()),
+        };
+
+        let review = match self.review.as_mut() {
+            Some(r) => r,
+            None => return Ok(()),
+        };
+
+        let body = edit_in_editor(terminal)?;
+
+        match pulls::create_review(
+            github_client,
+            &self.repo_info,
+            review.number,
+            body.trim(),
+            event,
+            review.pending.clone(),
+        )
+        .await
+        {
+            Ok(result) => {
+                review.pending.clear();
+                // Show submitted comments inline
+                if let Ok(c) = comments::get_all_from_review(
+                    github_client,
+                    &self.repo_info,
+                    &review.number,
+                    Sort::Created,
+                    Order::Asc,
+                )
+                .await
+                {
+                    review.comments = c;
+                }
+                self.status = result;
+            }
+            Err(er) => self.status = format!("Error: {er}"),
+        }
+        self.screen = Screen::Review;
+        Ok(())
+    }
+}
+
+impl Review {
+    fn select_file(&mut self, index: usize) {
+        if self.files.is_empty() {
+            return;
+        }
+        let index = index.min(self.files.len() - 1);
+        self.file_state.select(Some(index));
+        self.diff = parse_patch(&self.files[index].patch);
+        self.cursor = 0;
+    }
+
+    /// Rendered diff of selected file with inline comments and row of cursor
+    fn diff_text(&self) -> (Text<'static>, usize) {
+        let filename = match self.file_state.selected().and_then(|i| self.files.get(i)) {
+            Some(f) => f.filename.clone(),
+            None => return (Text::from("No changed files"), 0),
+        };
+
+        let mut lines = Vec::new();
+        let mut cursor_row = 0;
+
+        for (index, diff_line) in self.diff.iter().enumerate() {
+            let (sign, base) = match diff_line.kind {
+                DiffLineKind::Hunk => {
+                    lines.push(Line::from(Span::styled(
+                        diff_line.text.clone(),
+                        Style::new().fg(Color::Cyan),
+                    )));
+                    if index == self.cursor {
+                        cursor_row = lines.len() - 1;
+                    }
+                    continue;
+                }
+                DiffLineKind::Added => ("+", Style::new().bg(Color::Rgb(0, 60, 0))),
+                DiffLineKind::Removed => ("-", Style::new().bg(Color::Rgb(70, 0, 0))),
+                DiffLineKind::Context => (" ", Style::new()),
+            };
+
+            let number = match diff_line.new_line.or(diff_line.old_line) {
+                Some(n) => format!("{n:>5} "),
+                None => "      ".to_string(),
+            };
+            let mut spans = vec![
+                Span::styled(number, Style::new().fg(Color::DarkGray)),
+                Span::styled(sign.to_string(), base),
+            ];
+            spans.extend(highlight(&diff_line.text, &filename, base));
+
+            let mut line = Line::from(spans);
+            if index == self.cursor {
+                line = line.patch_style(Style::new().add_modifier(Modifier::REVERSED));
+                cursor_row = lines.len();
+            }
+            lines.push(line);
+
+            if let Some((line_number, side)) = comment_place(diff_line) {
+                for comment in self.comments.iter().filter(|c| {
+                    c.path == filename
+                        && c.line == line_number
+                        && c.side.clone().unwrap_or_default() == side
+                }) {
+                    let who = match &comment.user {
+                        Some(u) => u.login.clone(),
+                        None => String::new(),
+                    };
+                    for (i, body_line) in comment.body.lines().enumerate() {
+                        let prefix = if i == 0 {
+                            format!("{who}: ")
+                        } else {
+                            String::new()
+                        };
+                        lines.push(Line::from(Span::styled(
+                            format!("      │ {prefix}{body_line}"),
+                            Style::new().fg(Color::Yellow),
+                        )));
+                    }
+                }
+                for comment in self.pending.iter().filter(|c| {
+                    c.path == filename && c.line == line_number && c.side == side.to_string()
+                }) {
+                    for body_line in comment.body.lines() {
+                        lines.push(Line::from(Span::styled(
+                            format!("      │ (pending) {body_line}"),
+                            Style::new().fg(Color::Green),
+                        )));
+                    }
+                }
+            }
+        }
+
+        (Text::from(lines), cursor_row)
+    }
+}
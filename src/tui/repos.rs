@@ -0,0 +1,433 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::Path;
+
+use octorust::types::{IssuesListState, MinimalRepository};
+use octorust::Client;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Paragraph, Row, Table, TableState, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::cli_in::set_vars::{GitProtocol, IssuesListSorts, IssuesListStates};
+use crate::cli_parse::entities::ListIssueArgs;
+use crate::git_utils::repo_info::RepoInfo;
+use crate::git_utils::{local_repo, releases, repos};
+use crate::tui;
+
+const HELP: &str = "j/k move  / filter  s sort  S reverse  g clone  i issues  A archive  q quit";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SortColumn {
+    Name,
+    Language,
+    Stars,
+    OpenIssues,
+    Pushed,
+}
+
+impl SortColumn {
+    fn next(self) -> Self {
+        match self {
+            SortColumn::Name => SortColumn::Language,
+            SortColumn::Language => SortColumn::Stars,
+            SortColumn::Stars => SortColumn::OpenIssues,
+            SortColumn::OpenIssues => SortColumn::Pushed,
+            SortColumn::Pushed => SortColumn::Name,
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            SortColumn::Name => "Name",
+            SortColumn::Language => "Language",
+            SortColumn::Stars => "Stars",
+            SortColumn::OpenIssues => "Open issues",
+            SortColumn::Pushed => "Last push",
+        }
+    }
+}
+
+const COLUMNS: [SortColumn; 5] = [
+    SortColumn::Name,
+    SortColumn::Language,
+    SortColumn::Stars,
+    SortColumn::OpenIssues,
+    SortColumn::Pushed,
+];
+
+struct ReposTui {
+    owner: String,
+    repos: Vec<MinimalRepository>,
+    protocol: GitProtocol,
+    // Latest release of repo by full name (None if repo has no releases)
+    releases: HashMap<String, Option<String>>,
+    table_state: TableState,
+    sort: SortColumn,
+    reverse: bool,
+    filter: String,
+    filtering: bool,
+    confirm_archive: bool,
+    status: String,
+    exit: bool,
+}
+
+/// Run repos dashboard until user quit
+pub async fn run(
+    github_client: &Client,
+    owner: String,
+    repos: Vec<MinimalRepository>,
+    protocol: GitProtocol,
+) -> Result<(), Box<dyn Error>> {
+    let mut app = ReposTui {
+        owner,
+        repos,
+        protocol,
+        releases: HashMap::new(),
+        table_state: TableState::default(),
+        sort: SortColumn::Pushed,
+        reverse: true,
+        filter: String::new(),
+        filtering: false,
+        confirm_archive: false,
+        status: HELP.to_string(),
+        exit: false,
+    };
+    app.sort_repos();
+    app.table_state.select(Some(0));
+
+    let mut terminal = ratatui::init();
+    let result = app.run(github_client, &mut terminal).await;
+    ratatui::restore();
+
+    result
+}
+
+impl ReposTui {
+    async fn run(
+        &mut self,
+        github_client: &Client,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<(), Box<dyn Error>> {
+        while !self.exit {
+            self.load_release(github_client).await;
+            terminal.draw(|frame| self.draw(frame))?;
+
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    self.handle_key(key, github_client, terminal).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn sort_repos(&mut self) {
+        match self.sort {
+            SortColumn::Name => self.repos.sort_by_key(|r| r.name.to_lowercase()),
+            SortColumn::Language => self.repos.sort_by(|a, b| a.language.cmp(&b.language)),
+            SortColumn::Stars => self.repos.sort_by_key(|r| r.stargazers_count),
+            SortColumn::OpenIssues => self.repos.sort_by_key(|r| r.open_issues_count),
+            SortColumn::Pushed => self.repos.sort_by_key(|r| r.pushed_at),
+        }
+        if self.reverse {
+            self.repos.reverse();
+        }
+    }
+
+    /// Repos which contain filter in name, language or description
+    fn visible(&self) -> Vec<&MinimalRepository> {
+        let filter = self.filter.to_lowercase();
+        self.repos
+            .iter()
+            .filter(|r| {
+                filter.is_empty()
+                    || r.name.to_lowercase().contains(&filter)
+                    || r.language.to_lowercase().contains(&filter)
+                    || r.description.to_lowercase().contains(&filter)
+            })
+            .collect()
+    }
+
+    fn selected(&self) -> Option<&MinimalRepository> {
+        let index = self.table_state.selected()?;
+        self.visible().get(index).copied()
+    }
+
+    fn select(&mut self, index: usize) {
+        let len = self.visible().len();
+        self.table_state.select(if len == 0 {
+            None
+        } else {
+            Some(index.min(len - 1))
+        });
+        self.confirm_archive = false;
+    }
+
+    /// Load latest release of selected repo, if it was not loaded before
+    async fn load_release(&mut self, github_client: &Client) {
+        let repo = match self.selected() {
+            Some(r) => r,
+            None => return,
+        };
+        if self.releases.contains_key(&repo.full_name) {
+            return;
+        }
+        let full_name = repo.full_name.clone();
+
+        let release = match repo.full_name.parse::<RepoInfo>() {
+            Ok(repo_info) => releases::get_latest(github_client, repo_info)
+                .await
+                .ok()
+                .map(|r| format!("{} {}", r.tag_name, r.name)),
+            Err(_) => None,
+        };
+        self.releases.insert(full_name, release);
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main_area, status_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [table_area, detail_area] =
+            Layout::horizontal([Constraint::Percentage(65), Constraint::Percentage(35)])
+                .areas(main_area);
+
+        let header = Row::new(COLUMNS.map(|c| {
+            if c != self.sort {
+                c.title().to_string()
+            } else if self.reverse {
+                format!("{} ▼", c.title())
+            } else {
+                format!("{} ▲", c.title())
+            }
+        }))
+        .style(Style::new().add_modifier(Modifier::BOLD));
+
+        let rows: Vec<Row> = self
+            .visible()
+            .iter()
+            .map(|r| {
+                let pushed = match r.pushed_at {
+                    Some(time) => time.format("%Y-%m-%d").to_string(),
+                    None => String::new(),
+                };
+                let name = if r.archived {
+                    format!("{} (archived)", r.name)
+                } else {
+                    r.name.clone()
+                };
+                Row::new(vec![
+                    name,
+                    r.language.clone(),
+                    r.stargazers_count.to_string(),
+                    r.open_issues_count.to_string(),
+                    pushed,
+                ])
+            })
+            .collect();
+
+        let title = if self.filter.is_empty() {
+            format!(" Repos of {} ", self.owner)
+        } else {
+            format!(" Repos of {} [{}] ", self.owner, self.filter)
+        };
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(35),
+                Constraint::Percentage(20),
+                Constraint::Percentage(10),
+                Constraint::Percentage(15),
+                Constraint::Percentage(20),
+            ],
+        )
+        .header(header)
+        .block(Block::bordered().title(title))
+        .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(table, table_area, &mut self.table_state);
+
+        let detail = Paragraph::new(self.detail_text())
+            .block(Block::bordered().title(" Repo "))
+            .wrap(Wrap { trim: false });
+        frame.render_widget(detail, detail_area);
+
+        let status = if self.filtering {
+            format!("Filter: {}", self.filter)
+        } else {
+            self.status.clone()
+        };
+        frame.render_widget(Paragraph::new(status), status_area);
+    }
+
+    fn detail_text(&self) -> Text<'static> {
+        let repo = match self.selected() {
+            Some(r) => r,
+            None => return Text::from("Repo not find"),
+        };
+
+        let release = match self.releases.get(&repo.full_name) {
+            Some(Some(r)) => r.clone(),
+            Some(None) => "no releases".to_string(),
+            None => "Loading...".to_string(),
+        };
+
+        Text::from(vec![
+            Line::from(Span::styled(
+                repo.full_name.clone(),
+                Style::new().add_modifier(Modifier::BOLD),
+            )),
+            Line::from(repo.description.clone()),
+            Line::from(""),
+            Line::from(format!("Latest release: {release}")),
+            Line::from(format!(
+                "Open issues and pull requests: {}",
+                repo.open_issues_count
+            )),
+            Line::from(format!("Default branch: {}", repo.default_branch)),
+            Line::from(format!("Visibility: {}", repo.visibility)),
+            Line::from(format!("Url: {}", repo.html_url)),
+            Line::from(format!("Ssh: {}", repo.ssh_url)),
+        ])
+    }
+
+    async fn handle_key(
+        &mut self,
+        key: KeyEvent,
+        github_client: &Client,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<(), Box<dyn Error>> {
+        if self.filtering {
+            match key.code {
+                KeyCode::Esc => {
+                    self.filter.clear();
+                    self.filtering = false;
+                }
+                KeyCode::Enter => self.filtering = false,
+                KeyCode::Backspace => {
+                    self.filter.pop();
+                }
+                KeyCode::Char(c) => self.filter.push(c),
+                _ => {}
+            }
+            self.select(0);
+            return Ok(());
+        }
+
+        if self.confirm_archive {
+            self.confirm_archive = false;
+            if key.code == KeyCode::Char('y') {
+                self.archive(github_client).await;
+            } else {
+                self.status = HELP.to_string();
+            }
+            return Ok(());
+        }
+
+        let index = self.table_state.selected().unwrap_or(0);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.exit = true,
+            KeyCode::Char('j') | KeyCode::Down => self.select(index + 1),
+            KeyCode::Char('k') | KeyCode::Up => self.select(index.saturating_sub(1)),
+            KeyCode::Char('/') => self.filtering = true,
+            KeyCode::Char('s') => {
+                self.sort = self.sort.next();
+                self.sort_repos();
+                self.select(0);
+            }
+            KeyCode::Char('S') => {
+                self.reverse = !self.reverse;
+                self.sort_repos();
+                self.select(0);
+            }
+            KeyCode::Char('g') => self.clone_repo(terminal),
+            KeyCode::Char('i') => self.open_issues(github_client, terminal).await,
+            KeyCode::Char('A') => {
+                if let Some(repo) = self.selected() {
+                    self.status = format!("Archive {}? (y/n)", repo.full_name);
+                    self.confirm_archive = true;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Clone selected repo into current directory. Tui is suspended while git works,
+    /// so it can ask for ssh passphrase or credentials
+    fn clone_repo(&mut self, terminal: &mut DefaultTerminal) {
+        let repo_info = match self.selected().map(|r| r.full_name.parse::<RepoInfo>()) {
+            Some(Ok(r)) => r,
+            Some(Err(er)) => {
+                self.status = format!("Error: {er}");
+                return;
+            }
+            None => return,
+        };
+        let url = local_repo::clone_url(&repo_info, &self.protocol);
+
+        ratatui::restore();
+        println!("Cloning {url}");
+        let result = local_repo::clone(&url, Path::new(&repo_info.get_name()));
+        *terminal = ratatui::init();
+
+        self.status = match result {
+            Ok(()) => format!("{}/{} cloned", repo_info.get_owner(), repo_info.get_name()),
+            Err(er) => format!("Error: {}", er.to_string().replace('\n', " ")),
+        };
+    }
+
+    /// Open issues tui for selected repo and come back after it
+    async fn open_issues(&mut self, github_client: &Client, terminal: &mut DefaultTerminal) {
+        let repo_info = match self.selected().map(|r| r.full_name.parse::<RepoInfo>()) {
+            Some(Ok(r)) => r,
+            Some(Err(er)) => {
+                self.status = format!("Error: {er}");
+                return;
+            }
+            None => return,
+        };
+
+        let list_args = ListIssueArgs {
+            creator: String::new(),
+            assignee: String::new(),
+            state: IssuesListStates(IssuesListState::Open),
+            labels: String::new(),
+            milestone: None,
+            sort: IssuesListSorts::Created,
+            numb_of_page: 1,
+            iss_on_page: 30,
+        };
+
+        ratatui::restore();
+        let result = tui::issues::run(github_client, repo_info, list_args).await;
+        *terminal = ratatui::init();
+
+        if let Err(er) = result {
+            self.status = format!("Error: {er}");
+        }
+    }
+
+    async fn archive(&mut self, github_client: &Client) {
+        let repo_info = match self.selected().map(|r| r.full_name.parse::<RepoInfo>()) {
+            Some(Ok(r)) => r,
+            Some(Err(er)) => {
+                self.status = format!("Error: {er}");
+                return;
+            }
+            None => return,
+        };
+
+        match repos::set_archived(github_client, &repo_info, true).await {
+            Ok(_) => {
+                let full_name = format!("{}/{}", repo_info.get_owner(), repo_info.get_name());
+                if let Some(repo) = self.repos.iter_mut().find(|r| r.full_name == full_name) {
+                    repo.archived = true;
+                }
+                self.status = format!("{full_name} archived");
+            }
+            Err(er) => self.status = format!("Error: {er}"),
+        }
+    }
+}